@@ -1,4 +1,3 @@
-use uuid::Uuid;
 use yew::prelude::*;
 
 use ui_common::{components::*, Icon};
@@ -72,79 +71,6 @@ fn main() {
 
 #[function_component]
 fn PageContent() -> Html {
-    let dropdown_id = format!("dropdown-{}", Uuid::new_v4());
-    // let router = use_router().expect("no router found");
-
-    // let menu_open = {
-    //     let dropdown_id = dropdown_id.clone();
-
-    //     use_callback(dropdown_id, |e: MouseEvent, dropdown_id| {
-    //         e.prevent_default();
-    //         e.stop_propagation();
-
-    //         let document = web_sys::window()
-    //             .expect("no window found")
-    //             .document()
-    //             .expect("no document found");
-
-    //         // Remove all dropdowns
-    //         let dropdowns = document.query_selector_all("[data-dropdown=true]").unwrap();
-    //         for i in 0..dropdowns.length() {
-    //             let dd = dropdowns.get(i).unwrap().dyn_into::<HtmlElement>().unwrap();
-    //             dd.remove();
-    //         }
-
-    //         // Create dropdown element and append it to the body
-    //         let dd = document.create_element("div").unwrap();
-    //         dd.set_attribute("data-dropdown", "true").unwrap();
-    //         dd.set_inner_html(&dropdown(&DropdownProps {
-    //             id: dropdown_id.clone(),
-    //         }));
-    //         document.body().unwrap().append_child(&dd).unwrap();
-
-    //         let rect = e
-    //             .target()
-    //             .expect("mouse event doesn't have a target")
-    //             .dyn_into::<HtmlElement>()
-    //             .expect("event target should be of type HtmlElement")
-    //             .closest("button")
-    //             .expect("no button found")
-    //             .expect("no button found")
-    //             .get_bounding_client_rect();
-
-    //         // Position the dropdown
-    //         let dd = document.get_element_by_id(&dropdown_id).unwrap();
-    //         let dropdown_width = 240.0;
-
-    //         dd.set_attribute(
-    //             "style",
-    //             &format!(
-    //                 "top: {}px; left: {}px",
-    //                 rect.bottom() + 4.0,
-    //                 rect.right() - dropdown_width,
-    //             ),
-    //         )
-    //         .expect("failed to set style");
-    //     })
-    // };
-
-    // let menu_close = {
-    //     let dropdown_id = dropdown_id.clone();
-
-    //     use_callback(dropdown_id, |e: MouseEvent, dropdown_id| {
-    //         e.prevent_default();
-    //         e.stop_propagation();
-
-    //         let document = web_sys::window()
-    //             .expect("no window found")
-    //             .document()
-    //             .expect("no document found");
-
-    //         let dropdown = document.get_element_by_id(&dropdown_id).unwrap();
-    //         dropdown.class_list().toggle("hidden").unwrap();
-    //     })
-    // };
-
     let columns = vec![
         TableColumn {
             title: Some("Title".to_string()),
@@ -173,8 +99,24 @@ fn PageContent() -> Html {
         TableColumn {
             title: None,
             cell: TableCellRenderer::new(move |_| {
+                let items = vec![
+                    MenuItem::action("Edit", Callback::from(|_| ())).with_icon(Icon::PENCIL_SIMPLE),
+                    MenuItem::action("Duplicate", Callback::from(|_| ())).with_icon(Icon::COPY),
+                    MenuItem::submenu(
+                        "Move to",
+                        vec![
+                            MenuItem::action("Drafts", Callback::from(|_| ())),
+                            MenuItem::action("Archive", Callback::from(|_| ())),
+                        ],
+                    ),
+                    MenuItem::separator(),
+                    MenuItem::action("Delete", Callback::from(|_| ())).with_icon(Icon::TRASH),
+                ];
+
                 html! {
-                    <Button<Route> left_icon={Some(Icon::DOTS_THREE_VERTICAL)} color={ButtonColor::Blind} variant={ButtonVariant::Transparent} />
+                    <DropdownMenu {items} align={DropdownAlign::End} label="Row actions">
+                        <Button<Route> left_icon={Some(Icon::DOTS_THREE_VERTICAL)} color={ButtonColor::Blind} variant={ButtonVariant::Transparent} />
+                    </DropdownMenu>
                 }
             }),
         },
@@ -335,21 +277,3 @@ fn PageContent() -> Html {
         </>
     }
 }
-
-#[derive(Clone, Debug, PartialEq, Properties)]
-pub struct DropdownProps {
-    #[prop_or(format!("dropdown-{}", Uuid::new_v4()))]
-    pub id: String,
-}
-
-fn dropdown(props: &DropdownProps) -> String {
-    format!(
-        r#"
-        <div id={} class="absolute drop-shadow-md p-2 rounded-md border border-transparent-black-400 dark:border-transparent-white-400 bg-gray-high-100 dark:bg-gray-low-700 w-60 flex-rows text-gray-low-400 dark:text-gray-high-700">
-            <div class="py-2 px-3">test</div>
-            <div class="py-2 px-3">test 2</div>
-        </div>
-        "#,
-        props.id
-    )
-}
@@ -0,0 +1,46 @@
+//! Local WebSocket echo server, the stand-in backend of the `use_websocket` tests.
+//!
+//! Every text or binary message is sent back as is. A message containing `"drop"`
+//!  closes the connection without a close frame instead, like a server going away.
+//!
+//! ```sh
+//! cargo run --example echo_server -- 127.0.0.1:8080
+//! ```
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    use std::{net::TcpListener, thread};
+
+    use tungstenite::{accept, Message};
+
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:8080".to_string());
+    let listener = TcpListener::bind(&address).expect("Address must be available");
+    println!("Echo server listening on ws://{address}");
+
+    for stream in listener.incoming().flatten() {
+        thread::spawn(move || {
+            let Ok(mut socket) = accept(stream) else {
+                return;
+            };
+
+            while let Ok(message) = socket.read() {
+                let echo = match message {
+                    // Dropping the socket closes the TCP connection
+                    Message::Text(ref text) if text.contains("\"drop\"") => break,
+                    Message::Text(_) | Message::Binary(_) => message,
+                    _ => continue,
+                };
+
+                if socket.send(echo).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+// The server only runs natively, the crate itself targets the browser
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,245 @@
+use yew::prelude::*;
+use yew_hooks::use_local_storage;
+
+use crate::Icon;
+
+#[derive(PartialEq, Default, Clone, Copy, Debug)]
+pub enum Tone {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl Tone {
+    fn icon(&self) -> Icon {
+        match self {
+            Tone::Info => Icon::INFO,
+            Tone::Success => Icon::CHECK_CIRCLE,
+            Tone::Warning => Icon::WARNING,
+            Tone::Danger => Icon::WARNING_CIRCLE,
+        }
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct AlertProps {
+    /// Body of the alert
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Color and default icon of the alert
+    ///  default is `AlertTone::Info`
+    #[prop_or_default]
+    pub tone: Tone,
+
+    /// Icon replacing the one of the tone
+    #[prop_or_default]
+    pub icon: Option<Icon>,
+
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+
+    /// Buttons under the body, e.g. `Button`s
+    #[prop_or_default]
+    pub actions: Children,
+
+    /// Show a button which hides the alert
+    #[prop_or_default]
+    pub dismissible: bool,
+
+    /// Called when the alert is dismissed
+    #[prop_or_default]
+    pub on_dismiss: Callback<()>,
+
+    /// Full-width bar without rounded corners, to place under the `Header`
+    #[prop_or_default]
+    pub banner: bool,
+
+    /// Identifier of the alert, its dismissal is remembered in `localStorage`
+    ///  so it is not shown again, e.g. on the next visit
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Additional classes on top of the alert
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Alert component
+/// Page or form level message, e.g. "Your trial ends in 3 days" or a list of form errors.
+///
+/// With `banner` it spans the whole width of the page, its content aligned with
+///  the one of the [`Header`](super::Header). Dismissed alerts with an `id`
+///  stay hidden, their dismissal is stored in `localStorage`.
+///
+/// See [AlertProps](AlertProps) for more details.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use ui_common::components::{Alert, AlertTone, Button};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum Page {
+///     Upgrade,
+/// }
+///
+/// #[function_component(AlertExample)]
+/// fn alert_example() -> Html {
+///     let actions = html! {
+///         <Button<Page> text="Upgrade" />
+///     };
+///
+///     html! {
+///         <>
+///             <Alert
+///                 tone={AlertTone::Warning}
+///                 title="Your trial ends in 3 days"
+///                 banner={true}
+///                 dismissible={true}
+///                 id="trial-ends"
+///             >
+///                 {"Upgrade to keep your projects."}
+///             </Alert>
+///             <Alert tone={AlertTone::Danger} title="The form has 2 errors">
+///                 <ul>
+///                     <li>{"Name is required"}</li>
+///                     <li>{"Email is invalid"}</li>
+///                 </ul>
+///             </Alert>
+///             <Alert title="New version" {actions}>{"Reload to update."}</Alert>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Alert(
+    AlertProps {
+        children,
+        tone,
+        icon,
+        title,
+        actions,
+        dismissible,
+        on_dismiss,
+        banner,
+        id,
+        class,
+    }: &AlertProps,
+) -> Html {
+    let dismissed = use_state(|| false);
+    // One key per alert, so alerts dismissed at the same time don't overwrite each other
+    let stored = use_local_storage::<bool>(format!(
+        "mm-alert-dismissed-{}",
+        id.as_deref().unwrap_or_default()
+    ));
+
+    if *dismissed || (id.is_some() && *stored == Some(true)) {
+        return html! {};
+    }
+
+    let on_dismiss = {
+        let dismissed = dismissed.clone();
+        let persist = id.is_some();
+        let on_dismiss = on_dismiss.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            dismissed.set(true);
+            if persist {
+                stored.set(true);
+            }
+            on_dismiss.emit(());
+        })
+    };
+
+    let (tone_classes, icon_classes) = match tone {
+        Tone::Info => (
+            classes!(
+                "mm-bg-primary-50",
+                "dark:mm-bg-primary-950",
+                "mm-border-primary-200",
+                "dark:mm-border-primary-800"
+            ),
+            classes!("mm-text-primary-700", "dark:mm-text-primary-400"),
+        ),
+        Tone::Success => (
+            classes!(
+                "mm-bg-green-50",
+                "dark:mm-bg-green-950",
+                "mm-border-green-200",
+                "dark:mm-border-green-800"
+            ),
+            classes!("mm-text-green-600", "dark:mm-text-green-500"),
+        ),
+        Tone::Warning => (
+            classes!(
+                "mm-bg-amber-50",
+                "dark:mm-bg-amber-950",
+                "mm-border-amber-200",
+                "dark:mm-border-amber-800"
+            ),
+            classes!("mm-text-amber-500", "dark:mm-text-amber-400"),
+        ),
+        Tone::Danger => (
+            classes!(
+                "mm-bg-red-50",
+                "dark:mm-bg-red-950",
+                "mm-border-red-200",
+                "dark:mm-border-red-800"
+            ),
+            classes!("mm-text-red-600", "dark:mm-text-red-500"),
+        ),
+    };
+
+    let (shape_classes, content_classes) = if *banner {
+        (
+            classes!("mm-w-full", "mm-border-b"),
+            classes!("mm-container", "mm-mx-auto", "mm-px-6", "mm-py-3"),
+        )
+    } else {
+        (classes!("mm-rounded-lg", "mm-border"), classes!("mm-p-4"))
+    };
+
+    // Problems are announced right away, other messages when the reader is idle
+    let role = match tone {
+        Tone::Warning | Tone::Danger => "alert",
+        Tone::Info | Tone::Success => "status",
+    };
+
+    html! {
+        <div
+            {role}
+            class={classes!("mm-text-sm", "mm-text-gray-low-800", "dark:mm-text-gray-high-200", tone_classes, shape_classes, class.clone())}
+        >
+            <div class={classes!("mm-flex", "mm-items-start", "mm-gap-3", content_classes)}>
+                <span class={classes!("mm-shrink-0", "mm-text-xl", "mm-flex", icon_classes)}>
+                    { icon.unwrap_or_else(|| tone.icon()) }
+                </span>
+                <div class="mm-flex mm-flex-col mm-gap-1 mm-grow">
+                    if let Some(title) = title {
+                        <p class="mm-font-medium">{ title.clone() }</p>
+                    }
+                    if !children.is_empty() {
+                        <div class="mm-text-gray-low-300 dark:mm-text-gray-high-600">{ for children.iter() }</div>
+                    }
+                    if !actions.is_empty() {
+                        <div class="mm-flex mm-flex-wrap mm-gap-2 mm-pt-2">{ for actions.iter() }</div>
+                    }
+                </div>
+                if *dismissible {
+                    <button
+                        type="button"
+                        aria-label="Dismiss"
+                        class="mm-shrink-0 mm-inline-flex mm-items-center mm-rounded-full mm-text-xl mm-text-gray-low-100 dark:mm-text-gray-low-200 hover:mm-text-gray-low-800 dark:hover:mm-text-gray-high-200"
+                        onclick={on_dismiss}
+                    >
+                        { Icon::X }
+                    </button>
+                }
+            </div>
+        </div>
+    }
+}
@@ -1,3 +1,4 @@
+use unicode_segmentation::UnicodeSegmentation;
 use yew::prelude::*;
 use yew_nested_router::{components::*, prelude::*};
 
@@ -13,6 +14,28 @@ pub enum Size {
     ExtraLarge,
 }
 
+impl Size {
+    pub(crate) fn dimension_classes(&self) -> &'static str {
+        match self {
+            Size::ExtraSmall => "mm-w-6 mm-h-6",
+            Size::Small => "mm-w-7 mm-h-7",
+            Size::Medium => "mm-w-8 mm-h-8",
+            Size::Large => "mm-w-9 mm-h-9",
+            Size::ExtraLarge => "mm-w-12 mm-h-12",
+        }
+    }
+
+    /// Size of the initials, and of the counter of an `AvatarGroup`.
+    pub(crate) fn text_classes(&self) -> &'static str {
+        match self {
+            Size::ExtraSmall => "mm-text-[10px]",
+            Size::Small => "mm-text-xs",
+            Size::Medium | Size::Large => "mm-text-sm",
+            Size::ExtraLarge => "mm-text-lg",
+        }
+    }
+}
+
 #[derive(PartialEq, Default, Clone)]
 pub enum Variant {
     #[default]
@@ -21,15 +44,106 @@ pub enum Variant {
     Image,
 }
 
-/// Possible connection status.
+/// Possible connection status, or presence of the user.
 #[derive(Clone, Debug, PartialEq)]
 pub enum ConnectionStatus {
     Connecting,
     Open,
     Closed,
+
+    Online,
+    Away,
+    Busy,
+    Offline,
 }
 
-#[derive(PartialEq, Properties)]
+impl ConnectionStatus {
+    /// Text announced by screen readers for the status dot.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConnectionStatus::Connecting => "Connecting",
+            ConnectionStatus::Open => "Connected",
+            ConnectionStatus::Closed => "Disconnected",
+            ConnectionStatus::Online => "Online",
+            ConnectionStatus::Away => "Away",
+            ConnectionStatus::Busy => "Busy",
+            ConnectionStatus::Offline => "Offline",
+        }
+    }
+
+    fn color_classes(&self) -> &'static str {
+        match self {
+            ConnectionStatus::Connecting => "mm-bg-amber-600 dark:mm-bg-amber-500 mm-animate-ping",
+            ConnectionStatus::Open => "mm-bg-green-700",
+            ConnectionStatus::Closed => "mm-bg-gray-high-900 dark:mm-bg-gray-low-400",
+            ConnectionStatus::Online => "mm-bg-green-600 dark:mm-bg-green-500",
+            ConnectionStatus::Away => "mm-bg-amber-500 dark:mm-bg-amber-400",
+            ConnectionStatus::Busy => "mm-bg-red-600 dark:mm-bg-red-500",
+            ConnectionStatus::Offline => "mm-bg-gray-high-50 dark:mm-bg-gray-low-900 !mm-border-gray-high-900 dark:!mm-border-gray-low-400",
+        }
+    }
+}
+
+/// Background and text colours of the `Letter` variant, readable in light and dark mode.
+const LETTER_PALETTE: [&str; 8] = [
+    "mm-bg-red-100 mm-text-red-800 dark:mm-bg-red-900 dark:mm-text-red-200",
+    "mm-bg-orange-100 mm-text-orange-800 dark:mm-bg-orange-900 dark:mm-text-orange-200",
+    "mm-bg-amber-100 mm-text-amber-800 dark:mm-bg-amber-900 dark:mm-text-amber-200",
+    "mm-bg-emerald-100 mm-text-emerald-800 dark:mm-bg-emerald-900 dark:mm-text-emerald-200",
+    "mm-bg-teal-100 mm-text-teal-800 dark:mm-bg-teal-900 dark:mm-text-teal-200",
+    "mm-bg-sky-100 mm-text-sky-800 dark:mm-bg-sky-900 dark:mm-text-sky-200",
+    "mm-bg-primary-100 mm-text-primary-800 dark:mm-bg-primary-900 dark:mm-text-primary-200",
+    "mm-bg-pink-100 mm-text-pink-800 dark:mm-bg-pink-900 dark:mm-text-pink-200",
+];
+
+/// Up to two initials: the first letters of the first and the last word of the name.
+///
+/// Letters are whole grapheme clusters, so accents and emoji are never split, and
+///  punctuation around the words is ignored.
+///
+/// ```rust
+/// use ui_common::components::avatar_initials;
+///
+/// assert_eq!(avatar_initials("Rinat Shay."), "RS");
+/// assert_eq!(avatar_initials("ada"), "A");
+/// assert_eq!(avatar_initials("Jean-Luc de la Fontaine"), "JF");
+/// assert_eq!(avatar_initials("e\u{301}mile zola"), "E\u{301}Z");
+/// assert_eq!(avatar_initials("(Ørjan)  Åsen"), "ØÅ");
+/// assert_eq!(avatar_initials(" … "), "");
+/// ```
+pub fn initials(name: &str) -> String {
+    let letters = name
+        .split_whitespace()
+        .filter_map(|word| {
+            word.graphemes(true)
+                .find(|grapheme| grapheme.chars().next().is_some_and(char::is_alphanumeric))
+        })
+        .collect::<Vec<_>>();
+
+    let initials = match letters[..] {
+        [] => Vec::new(),
+        [only] => vec![only],
+        [first, .., last] => vec![first, last],
+    };
+
+    initials.concat().to_uppercase()
+}
+
+/// Colour classes of the `Letter` variant, always the same for the same name.
+fn letter_color(name: &str) -> &'static str {
+    // FNV-1a, unlike `DefaultHasher` it is stable across Rust releases
+    let hash = name
+        .trim()
+        .to_lowercase()
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+
+    LETTER_PALETTE[(hash % LETTER_PALETTE.len() as u64) as usize]
+}
+
+#[derive(Clone, PartialEq, Properties)]
 pub struct Props<T>
 where
     T: Target,
@@ -50,6 +164,22 @@ where
     #[prop_or_default]
     pub name: AttrValue,
 
+    /// URL of the image, for the `Image` variant.
+    #[prop_or_default]
+    pub src: Option<AttrValue>,
+
+    /// Alternative text of the image, defaults to `name`.
+    #[prop_or_default]
+    pub alt: Option<AttrValue>,
+
+    /// Candidate images for other pixel densities, e.g. `avatar@2x.png 2x`.
+    #[prop_or_default]
+    pub srcset: Option<AttrValue>,
+
+    /// Defer loading the image until it is close to the viewport.
+    #[prop_or_default]
+    pub lazy: bool,
+
     /// Target to navigate to.
     #[prop_or_default]
     pub to: Option<T>,
@@ -58,39 +188,100 @@ where
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
 
-    /// Websocket connection status.
+    /// Websocket connection status, or presence of the user.
     #[prop_or_default]
     pub connection_status: Option<ConnectionStatus>,
 
+    /// Accessible label of the status dot, defaults to [`ConnectionStatus::label`].
+    #[prop_or_default]
+    pub status_label: Option<AttrValue>,
+
+    /// Content shown on the top-right corner, e.g. an unread count.
+    #[prop_or_default]
+    pub badge: Option<Html>,
+
     /// List of classes to apply.
     #[prop_or_default]
     pub class: Classes,
 }
 
+/// # Avatar component
+/// Round icon, initials or image, optionally linking to a target.
+///
+/// When the image is missing or fails to load, the avatar falls back to the `Letter` variant
+///  when a `name` is set, and to the `Icon` variant otherwise.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use ui_common::components::{Avatar, AvatarConnectionStatus, AvatarSize, AvatarVariant};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum Page {
+///     Profile,
+/// }
+///
+/// #[function_component(AvatarExample)]
+/// fn avatar_example() -> Html {
+///     html! {
+///         <Avatar<Page>
+///             variant={AvatarVariant::Image}
+///             size={AvatarSize::Large}
+///             name="Rinat Shay"
+///             src="/avatars/rinat.png"
+///             srcset="/avatars/rinat@2x.png 2x"
+///             lazy=true
+///             to={Page::Profile}
+///             connection_status={AvatarConnectionStatus::Away}
+///             badge={html! { "3" }}
+///         />
+///     }
+/// }
+/// ```
 #[function_component]
 pub fn Avatar<T>(props: &Props<T>) -> Html
 where
     T: Target + 'static,
 {
-    let dimension_classes = match props.size {
-        Size::ExtraSmall => "mm-w-6 mm-h-6",
-        Size::Small => "mm-w-7 mm-h-7",
-        Size::Medium => "mm-w-8 mm-h-8",
-        Size::Large => "mm-w-9 mm-h-9",
-        Size::ExtraLarge => "mm-w-12 mm-h-12",
+    let failed = use_state(|| false);
+
+    // A new image gets a new chance to load
+    use_effect_with(props.src.clone(), {
+        let failed = failed.clone();
+
+        move |_| failed.set(false)
+    });
+
+    let variant = match props.variant {
+        Variant::Image if props.src.is_none() || *failed => match props.name.is_empty() {
+            true => Variant::Icon,
+            false => Variant::Letter,
+        },
+        ref variant => variant.clone(),
     };
 
-    let color_classes = match props.variant {
-        Variant::Icon | Variant::Letter => "mm-border mm-border-transparent-black-400 dark:mm-border-transparent-white-400 mm-text-gray-low-100 dark:mm-text-gray-low-200",
+    let onerror = {
+        let failed = failed.clone();
+
+        Callback::from(move |_| failed.set(true))
+    };
+
+    let color_classes = match variant {
+        Variant::Icon => "mm-border mm-border-transparent-black-400 dark:mm-border-transparent-white-400 mm-text-gray-low-100 dark:mm-text-gray-low-200",
+        Variant::Letter => "mm-border mm-border-transparent-black-400 dark:mm-border-transparent-white-400",
         Variant::Image => "mm-border mm-border-transparent",
     };
 
-    let bg_classes = "mm-bg-gray-high-300 dark:mm-bg-gray-low-900";
+    let bg_classes = match variant {
+        Variant::Letter => letter_color(&props.name),
+        Variant::Icon | Variant::Image => "mm-bg-gray-high-300 dark:mm-bg-gray-low-900",
+    };
 
     let class = classes!(
         "mm-inline-flex",
         bg_classes,
-        dimension_classes,
+        props.size.dimension_classes(),
         color_classes,
         "mm-rounded-full",
         "mm-transition-colors",
@@ -102,28 +293,65 @@ where
         props.class.clone(),
     );
 
-    // TODO: sizing only implemented for the default (`Medium`) variant. Implement for others.
-    let connection_status = match props.connection_status {
-        Some(ConnectionStatus::Connecting) => html! {
-            <span class="mm-absolute mm-w-[7px] mm-h-[7px] mm-bg-amber-600 dark:mm-bg-amber-500 mm-rounded-full mm-bottom-px mm-right-px mm-border-[0.5px] mm-border-black-800 dark:mm-border-white-800 mm-animate-ping"></span>
-        },
-        Some(ConnectionStatus::Open) => html! {
-            <span class="mm-absolute mm-w-[7px] mm-h-[7px] mm-bg-green-700 mm-rounded-full mm-bottom-px mm-right-px mm-border-[0.5px] mm-border-black-800 dark:mm-border-white-800"></span>
-        },
-        Some(ConnectionStatus::Closed) => html! {
-            <span class="mm-absolute mm-w-[7px] mm-h-[7px] mm-bg-gray-high-900 dark:mm-bg-gray-low-400 mm-rounded-full mm-bottom-px mm-right-px mm-border-[0.5px] mm-border-black-800 dark:mm-border-white-800"></span>
+    let connection_status = match &props.connection_status {
+        Some(status) => {
+            let dot_classes = match props.size {
+                Size::ExtraSmall => {
+                    "mm-w-[5px] mm-h-[5px] mm-bottom-0 mm-right-0 mm-border-[0.5px]"
+                }
+                Size::Small => "mm-w-1.5 mm-h-1.5 mm-bottom-0 mm-right-0 mm-border-[0.5px]",
+                Size::Medium => "mm-w-[7px] mm-h-[7px] mm-bottom-px mm-right-px mm-border-[0.5px]",
+                Size::Large => "mm-w-2 mm-h-2 mm-bottom-px mm-right-px mm-border",
+                Size::ExtraLarge => "mm-w-3 mm-h-3 mm-bottom-0.5 mm-right-0.5 mm-border",
+            };
+            let label = props
+                .status_label
+                .clone()
+                .unwrap_or_else(|| AttrValue::from(status.label()));
+
+            html! {
+                <span
+                    class={classes!(
+                        "mm-absolute",
+                        "mm-rounded-full",
+                        "mm-border-black-800",
+                        "dark:mm-border-white-800",
+                        dot_classes,
+                        status.color_classes(),
+                    )}
+                    role="img"
+                    aria-label={label.clone()}
+                    title={label}
+                ></span>
+            }
+        }
+        None => html! {},
+    };
+
+    let badge = match &props.badge {
+        Some(badge) => html! {
+            <span class="mm-absolute -mm-top-1 -mm-right-1 mm-inline-flex mm-items-center mm-justify-center mm-min-w-4 mm-h-4 mm-px-1 mm-rounded-full mm-text-[10px] mm-font-medium mm-leading-none mm-text-white mm-bg-red-600 dark:mm-bg-red-500">
+                { badge.clone() }
+            </span>
         },
         None => html! {},
     };
 
+    let content = AvatarContentProps {
+        variant,
+        onerror,
+        ..props.into()
+    };
+
     if let Some(to) = &props.to {
         return html! {
             <Link<T>
                 class={classes!(class, "mm-inline-block")}
                 to={ to.clone() }
             >
-                <AvatarContent ..props.into() />
+                <AvatarContent ..content />
                 { connection_status }
+                { badge }
             </Link<T>>
         };
     }
@@ -134,8 +362,9 @@ where
             type="button"
             onclick={props.onclick.clone()}
         >
-            <AvatarContent ..props.into() />
+            <AvatarContent ..content />
             { connection_status }
+            { badge }
         </button>
     };
 }
@@ -146,6 +375,10 @@ pub struct AvatarContentProps {
     #[prop_or_default]
     pub variant: Variant,
 
+    /// Size of the avatar.
+    #[prop_or_default]
+    pub size: Size,
+
     /// Icon to display.
     #[prop_or(Icon::USER)]
     pub icon: Icon,
@@ -153,6 +386,19 @@ pub struct AvatarContentProps {
     /// Text to display.
     #[prop_or_default]
     pub name: AttrValue,
+
+    #[prop_or_default]
+    pub src: Option<AttrValue>,
+    #[prop_or_default]
+    pub alt: Option<AttrValue>,
+    #[prop_or_default]
+    pub srcset: Option<AttrValue>,
+    #[prop_or_default]
+    pub lazy: bool,
+
+    /// Emitted when the image fails to load.
+    #[prop_or_default]
+    pub onerror: Callback<()>,
 }
 
 impl<T> From<&Props<T>> for AvatarContentProps
@@ -162,8 +408,14 @@ where
     fn from(props: &Props<T>) -> Self {
         AvatarContentProps {
             variant: props.variant.clone(),
+            size: props.size.clone(),
             icon: props.icon,
             name: props.name.clone(),
+            src: props.src.clone(),
+            alt: props.alt.clone(),
+            srcset: props.srcset.clone(),
+            lazy: props.lazy,
+            onerror: Callback::noop(),
         }
     }
 }
@@ -174,12 +426,19 @@ fn AvatarContent(props: &AvatarContentProps) -> Html {
         if props.variant == Variant::Icon {
             <span class="mm-w-5 mm-min-h-5 mm-inline-block mm-justify-center mm-items-center mm-flex mm-text-xl">{ props.icon }</span>
         } else if props.variant == Variant::Letter {
-            <span class="mm-text-sm mm-uppercase">{ props.name.clone().chars().next().unwrap_or_default().to_string() }</span>
+            <span class={classes!("mm-font-medium", "mm-leading-none", "mm-select-none", props.size.text_classes())}>{ initials(&props.name) }</span>
         } else if props.variant == Variant::Image {
             <img
-                class="mm-w-10 mm-h-10 mm-rounded-full"
-                src="#"
-                alt="Avatar"
+                class="mm-w-full mm-h-full mm-object-cover mm-rounded-full"
+                src={props.src.clone()}
+                srcset={props.srcset.clone()}
+                alt={props.alt.clone().unwrap_or_else(|| match props.name.is_empty() {
+                    true => AttrValue::from("Avatar"),
+                    false => props.name.clone(),
+                })}
+                loading={props.lazy.then_some("lazy")}
+                decoding="async"
+                onerror={props.onerror.reform(|_: Event| ())}
             />
         }
     }
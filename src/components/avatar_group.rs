@@ -0,0 +1,152 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_nested_router::prelude::*;
+
+use super::{
+    avatar::{Avatar, Size},
+    tooltip::{Tooltip, TooltipPosition},
+};
+
+#[derive(PartialEq, Properties)]
+pub struct Props<T>
+where
+    T: Target,
+{
+    /// Avatars to stack, their size is replaced by the size of the group.
+    pub children: ChildrenWithProps<Avatar<T>>,
+
+    /// Maximum number of avatars to show, the others are counted in a "+N" bubble.
+    #[prop_or(4)]
+    pub max: usize,
+
+    /// Size of every avatar of the group.
+    #[prop_or_default]
+    pub size: Size,
+
+    /// Accessible label of the group, e.g. "Team members".
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # AvatarGroup component
+/// Overlapping [`Avatar`](super::Avatar)s. Avatars above `max` are replaced by a counter
+///  whose tooltip lists their names.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use ui_common::components::{Avatar, AvatarGroup, AvatarSize, AvatarVariant};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum Page {
+///     Team,
+/// }
+///
+/// #[function_component(AvatarGroupExample)]
+/// fn avatar_group_example() -> Html {
+///     let members = ["Rinat Shay", "Ada Lovelace", "Alan Turing", "Grace Hopper", "Linus Torvalds"];
+///
+///     html! {
+///         <AvatarGroup<Page> max={3} size={AvatarSize::Small} label="Team members">
+///             { for members.iter().map(|name| html_nested! {
+///                 <Avatar<Page> variant={AvatarVariant::Letter} name={*name} />
+///             }) }
+///         </AvatarGroup<Page>>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn AvatarGroup<T>(props: &Props<T>) -> Html
+where
+    T: Target + 'static,
+{
+    let (overlap_classes, ring_classes) = match props.size {
+        Size::ExtraSmall => ("-mm-space-x-1.5", "mm-ring-1"),
+        Size::Small => ("-mm-space-x-2", "mm-ring-2"),
+        Size::Medium => ("-mm-space-x-2", "mm-ring-2"),
+        Size::Large => ("-mm-space-x-2.5", "mm-ring-2"),
+        Size::ExtraLarge => ("-mm-space-x-3", "mm-ring-[3px]"),
+    };
+    let ring_classes = classes!(
+        ring_classes,
+        "mm-ring-gray-high-50",
+        "dark:mm-ring-gray-low-950"
+    );
+
+    let count = props.children.len();
+    // The counter takes the place of the last visible avatar
+    let visible = match count > props.max {
+        true => props.max.saturating_sub(1),
+        false => count,
+    };
+
+    let avatars = props.children.iter().take(visible).map(|mut avatar| {
+        let avatar_props = Rc::make_mut(&mut avatar.props);
+        avatar_props.size = props.size.clone();
+        avatar_props.class = classes!(
+            avatar_props.class.clone(),
+            ring_classes.clone(),
+            "hover:mm-z-10"
+        );
+
+        avatar
+    });
+
+    let hidden = props
+        .children
+        .iter()
+        .skip(visible)
+        .map(|avatar| avatar.props.name.clone())
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>();
+
+    let counter = (visible < count).then(|| {
+        let class = classes!(
+            "mm-inline-flex",
+            "mm-items-center",
+            "mm-justify-center",
+            "mm-rounded-full",
+            "mm-font-medium",
+            "mm-select-none",
+            "mm-bg-gray-high-300",
+            "dark:mm-bg-gray-low-900",
+            "mm-text-gray-low-800",
+            "dark:mm-text-gray-high-200",
+            props.size.dimension_classes(),
+            props.size.text_classes(),
+            ring_classes.clone(),
+        );
+        let more = count - visible;
+        let label = format!("{more} more");
+
+        let counter = html! {
+            <span {class} role="img" aria-label={label}>{ format!("+{more}") }</span>
+        };
+
+        match hidden.is_empty() {
+            true => counter,
+            false => html! {
+                <Tooltip title={hidden.join(", ")} position={TooltipPosition::Top}>
+                    { counter }
+                </Tooltip>
+            },
+        }
+    });
+
+    html! {
+        <div
+            class={classes!("mm-flex", "mm-items-center", overlap_classes, props.class.clone())}
+            role="group"
+            aria-label={props.label.clone()}
+        >
+            { for avatars }
+            { counter }
+        </div>
+    }
+}
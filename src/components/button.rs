@@ -45,6 +45,13 @@ pub enum Width {
     Full,
 }
 
+/// Route of the buttons rendered by the library itself, which never link anywhere.
+///  The derive needs a variant, it is never constructed.
+#[derive(Clone, Debug, PartialEq, Eq, Target)]
+pub(crate) enum NoRoute {
+    Never,
+}
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct Props<T>
 where
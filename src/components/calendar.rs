@@ -0,0 +1,473 @@
+use chrono::{Datelike, Days, Local, Months, NaiveDate, NaiveTime, Weekday};
+use gloo_utils::window;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+use crate::Icon;
+
+/// Regions which start the week on Sunday or Saturday (CLDR `firstDay`),
+///  every other region starts it on Monday.
+const SUNDAY_FIRST: &[&str] = &[
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO", "DM", "DO", "ET", "GT", "GU",
+    "HK", "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX",
+    "MZ", "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW",
+    "UM", "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+const SATURDAY_FIRST: &[&str] = &[
+    "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY",
+];
+
+/// Formats tried by [`parse_date`], after the numeric ones.
+const DATE_FORMATS: &[&str] = &["%d %B %Y", "%B %d %Y", "%d %b %Y", "%b %d %Y", "%Y%m%d"];
+
+/// Classes of the popup which holds the calendar of a picker.
+pub(crate) const POPUP_CLASSES: &str = "mm-absolute mm-z-10 mm-left-0 mm-top-full mm-mt-1 mm-flex mm-rounded-md mm-drop-shadow-md mm-border mm-border-transparent-black-400 dark:mm-border-transparent-white-400 mm-bg-gray-high-100 dark:mm-bg-gray-low-700";
+
+/// Language tag of the browser, e.g. `en-US`.
+pub(crate) fn browser_locale() -> String {
+    window()
+        .navigator()
+        .language()
+        .unwrap_or_else(|| "en-US".to_string())
+}
+
+/// First day of the week for a BCP 47 language tag such as `en-US` or `de-DE`.
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use ui_common::components::week_start_for_locale;
+///
+/// assert_eq!(week_start_for_locale("en-US"), Weekday::Sun);
+/// assert_eq!(week_start_for_locale("de-DE"), Weekday::Mon);
+/// assert_eq!(week_start_for_locale("ar-EG"), Weekday::Sat);
+/// ```
+pub fn week_start_for_locale(locale: &str) -> Weekday {
+    let region = locale
+        .split(['-', '_'])
+        .skip(1)
+        .find(|part| part.len() == 2 && part.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(|part| part.to_ascii_uppercase());
+
+    match region.as_deref() {
+        Some(region) if SUNDAY_FIRST.contains(&region) => Weekday::Sun,
+        Some(region) if SATURDAY_FIRST.contains(&region) => Weekday::Sat,
+        _ => Weekday::Mon,
+    }
+}
+
+/// Whether numeric dates are written month first (`03/14/2024`) in the given locale.
+pub(crate) fn month_first(locale: &str) -> bool {
+    locale.ends_with("-US") || locale.ends_with("_US") || locale.ends_with("-PH")
+}
+
+/// Parses a date typed by the user.
+///
+/// Accepts ISO dates, numeric dates with `/`, `.` or `-` separators (day or month first
+///  according to `month_first`), two-digit years, month names and the words `today`,
+///  `tomorrow` and `yesterday`.
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use ui_common::components::parse_date;
+///
+/// let today = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+///
+/// assert_eq!(parse_date("2024-03-01", today, false), NaiveDate::from_ymd_opt(2024, 3, 1));
+/// assert_eq!(parse_date("1.3.24", today, false), NaiveDate::from_ymd_opt(2024, 3, 1));
+/// assert_eq!(parse_date("3/1/2024", today, true), NaiveDate::from_ymd_opt(2024, 3, 1));
+/// assert_eq!(parse_date("1 March, 2024", today, false), NaiveDate::from_ymd_opt(2024, 3, 1));
+/// assert_eq!(parse_date("tomorrow", today, false), NaiveDate::from_ymd_opt(2024, 3, 15));
+/// assert_eq!(parse_date("31.02.2024", today, false), None);
+/// ```
+pub fn parse_date(input: &str, today: NaiveDate, month_first: bool) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+
+    match input.as_str() {
+        "" => return None,
+        "today" => return Some(today),
+        "tomorrow" => return today.checked_add_days(Days::new(1)),
+        "yesterday" => return today.checked_sub_days(Days::new(1)),
+        _ => {}
+    }
+
+    let parts = input
+        .split(['-', '/', '.', ' '])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>();
+
+    if parts.len() == 3
+        && parts
+            .iter()
+            .all(|part| part.chars().all(|c| c.is_ascii_digit()))
+    {
+        let numbers = parts
+            .iter()
+            .map(|part| part.parse::<u32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        let (year, month, day) = if parts[0].len() == 4 {
+            (numbers[0], numbers[1], numbers[2])
+        } else if month_first {
+            (numbers[2], numbers[0], numbers[1])
+        } else {
+            (numbers[2], numbers[1], numbers[0])
+        };
+
+        // Two-digit years are within the current century
+        let year = if year < 100 {
+            (today.year() as u32 / 100) * 100 + year
+        } else {
+            year
+        };
+
+        return NaiveDate::from_ymd_opt(year as i32, month, day);
+    }
+
+    let input = input.replace(',', " ");
+    let input = input.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(&input, format).ok())
+}
+
+/// Parses a time typed by the user.
+///
+/// Accepts 24-hour and 12-hour clocks with `:` or `.` separators, with or without minutes.
+///
+/// ```rust
+/// use chrono::NaiveTime;
+/// use ui_common::components::parse_time;
+///
+/// assert_eq!(parse_time("14:30"), NaiveTime::from_hms_opt(14, 30, 0));
+/// assert_eq!(parse_time("1430"), NaiveTime::from_hms_opt(14, 30, 0));
+/// assert_eq!(parse_time("2.30 pm"), NaiveTime::from_hms_opt(14, 30, 0));
+/// assert_eq!(parse_time("12am"), NaiveTime::from_hms_opt(0, 0, 0));
+/// assert_eq!(parse_time("9"), NaiveTime::from_hms_opt(9, 0, 0));
+/// assert_eq!(parse_time("25:00"), None);
+/// ```
+pub fn parse_time(input: &str) -> Option<NaiveTime> {
+    let input = input.trim().to_lowercase().replace(' ', "");
+
+    let (input, meridiem) =
+        if let Some(input) = input.strip_suffix("am").or_else(|| input.strip_suffix('a')) {
+            (input, Some(false))
+        } else if let Some(input) = input.strip_suffix("pm").or_else(|| input.strip_suffix('p')) {
+            (input, Some(true))
+        } else {
+            (input.as_str(), None)
+        };
+
+    let parts = if input.contains([':', '.']) {
+        input.split([':', '.']).collect::<Vec<_>>()
+    } else if input.len() > 2 {
+        // `930` or `1430`
+        let (hour, minute) = input.split_at(input.len() - 2);
+        vec![hour, minute]
+    } else {
+        vec![input]
+    };
+
+    let numbers = parts
+        .iter()
+        .map(|part| part.parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let (hour, minute, second) = match numbers[..] {
+        [hour] => (hour, 0, 0),
+        [hour, minute] => (hour, minute, 0),
+        [hour, minute, second] => (hour, minute, second),
+        _ => return None,
+    };
+
+    let hour = match meridiem {
+        Some(_) if hour == 0 || hour > 12 => return None,
+        Some(false) => hour % 12,
+        Some(true) => hour % 12 + 12,
+        None => hour,
+    };
+
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// Today in the local time zone of the browser.
+pub(crate) fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// Whether the date can be chosen.
+pub(crate) fn is_selectable(
+    date: NaiveDate,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+    disabled: &Option<Callback<NaiveDate, bool>>,
+) -> bool {
+    min.is_none_or(|min| date >= min)
+        && max.is_none_or(|max| date <= max)
+        && !disabled
+            .as_ref()
+            .is_some_and(|disabled| disabled.emit(date))
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("Every month has a first day")
+}
+
+#[derive(PartialEq, Properties)]
+pub(crate) struct CalendarProps {
+    /// Date focused when the calendar is shown.
+    pub initial: NaiveDate,
+
+    /// Start of the selection.
+    pub start: Option<NaiveDate>,
+
+    /// End of the selection, same as `start` for a single date.
+    pub end: Option<NaiveDate>,
+
+    pub min: Option<NaiveDate>,
+    pub max: Option<NaiveDate>,
+
+    /// Returns `true` for the dates which can't be chosen.
+    pub disabled: Option<Callback<NaiveDate, bool>>,
+
+    pub week_start: Weekday,
+
+    /// Emits the chosen date.
+    pub onselect: Callback<NaiveDate>,
+
+    /// Emits the date under the pointer, used to preview a range.
+    #[prop_or_default]
+    pub onhover: Callback<NaiveDate>,
+}
+
+/// Month grid following the ARIA date picker dialog pattern.
+#[function_component]
+pub(crate) fn Calendar(props: &CalendarProps) -> Html {
+    let focused = use_state(|| props.initial);
+    let node = use_node_ref();
+    // The focus follows the focused date only after keyboard navigation,
+    //  so the calendar doesn't steal it from a text field
+    let keyboard = use_mut_ref(|| false);
+
+    use_effect_with(props.initial, {
+        let focused = focused.clone();
+
+        move |initial| focused.set(*initial)
+    });
+
+    use_effect_with(*focused, {
+        let node = node.clone();
+        let keyboard = keyboard.clone();
+
+        move |focused| {
+            if !*keyboard.borrow() {
+                return;
+            }
+
+            if let Some(grid) = node.cast::<Element>() {
+                if let Ok(Some(day)) =
+                    grid.query_selector(&format!("[data-date=\"{}\"]", focused.format("%Y-%m-%d")))
+                {
+                    if let Some(day) = day.dyn_ref::<HtmlElement>() {
+                        let _ = day.focus();
+                    }
+                }
+            }
+        }
+    });
+
+    let month = first_of_month(*focused);
+    let offset =
+        (month.weekday().num_days_from_monday() + 7 - props.week_start.num_days_from_monday()) % 7;
+    let grid_start = month - Days::new(offset as u64);
+    let today = today();
+
+    let move_focus = {
+        let focused = focused.clone();
+
+        move |date: Option<NaiveDate>| {
+            if let Some(date) = date {
+                focused.set(date);
+            }
+        }
+    };
+
+    let previous_month = {
+        let move_focus = move_focus.clone();
+        let focused = *focused;
+
+        move |_| move_focus(focused.checked_sub_months(Months::new(1)))
+    };
+
+    let next_month = {
+        let move_focus = move_focus.clone();
+        let focused = *focused;
+
+        move |_| move_focus(focused.checked_add_months(Months::new(1)))
+    };
+
+    let onkeydown = {
+        let focused = *focused;
+        let onselect = props.onselect.clone();
+        let keyboard = keyboard.clone();
+        let (min, max, disabled) = (props.min, props.max, props.disabled.clone());
+        let week_start = props.week_start;
+
+        move |event: KeyboardEvent| {
+            let days_from_week_start = (focused.weekday().num_days_from_monday() + 7
+                - week_start.num_days_from_monday())
+                % 7;
+
+            let target = match event.key().as_str() {
+                "ArrowLeft" => focused.checked_sub_days(Days::new(1)),
+                "ArrowRight" => focused.checked_add_days(Days::new(1)),
+                "ArrowUp" => focused.checked_sub_days(Days::new(7)),
+                "ArrowDown" => focused.checked_add_days(Days::new(7)),
+                "Home" => focused.checked_sub_days(Days::new(days_from_week_start as u64)),
+                "End" => focused.checked_add_days(Days::new(6 - days_from_week_start as u64)),
+                "PageUp" if event.shift_key() => focused.checked_sub_months(Months::new(12)),
+                "PageDown" if event.shift_key() => focused.checked_add_months(Months::new(12)),
+                "PageUp" => focused.checked_sub_months(Months::new(1)),
+                "PageDown" => focused.checked_add_months(Months::new(1)),
+                "Enter" | " " => {
+                    event.prevent_default();
+                    if is_selectable(focused, min, max, &disabled) {
+                        onselect.emit(focused);
+                    }
+                    return;
+                }
+                _ => return,
+            };
+
+            event.prevent_default();
+            *keyboard.borrow_mut() = true;
+            move_focus(target);
+        }
+    };
+
+    let weekdays = (0..7)
+        .map(|index| {
+            let weekday = (grid_start + Days::new(index)).weekday();
+
+            html! {
+                <th scope="col" abbr={format!("{weekday:?}")} class="mm-w-9 mm-h-8 mm-text-xs mm-font-normal mm-text-gray-low-100 dark:mm-text-gray-low-200">
+                    { format!("{weekday:?}").chars().take(2).collect::<String>() }
+                </th>
+            }
+        })
+        .collect::<Html>();
+
+    let weeks = (0..6)
+        .map(|week| {
+            let days = (0..7)
+                .map(|day| {
+                    let date = grid_start + Days::new(week * 7 + day);
+                    let selectable = is_selectable(date, props.min, props.max, &props.disabled);
+
+                    let (start, end) = match (props.start, props.end) {
+                        (Some(start), Some(end)) if end < start => (Some(end), Some(start)),
+                        range => range,
+                    };
+                    let endpoint = Some(date) == start || Some(date) == end;
+                    let in_range = matches!((start, end), (Some(start), Some(end)) if date > start && date < end);
+
+                    let class = classes!(
+                        "mm-w-9",
+                        "mm-h-9",
+                        "mm-text-sm",
+                        "mm-transition-colors",
+                        "mm-duration-125",
+                        "mm-outline-none",
+                        "focus-visible:mm-ring-2",
+                        "focus-visible:mm-ring-primary-500",
+                        if in_range { "mm-rounded-none" } else { "mm-rounded-md" },
+                        if endpoint {
+                            "mm-bg-primary-700 dark:mm-bg-primary-500 mm-text-white hover:mm-bg-primary-600"
+                        } else if in_range {
+                            "mm-bg-primary-100 dark:mm-bg-primary-950 mm-text-gray-low-800 dark:mm-text-gray-high-200"
+                        } else if date.month() != month.month() {
+                            "mm-text-gray-high-900 dark:mm-text-gray-low-400 hover:mm-bg-transparent-black-300 dark:hover:mm-bg-transparent-white-300"
+                        } else {
+                            "mm-text-gray-low-800 dark:mm-text-gray-high-200 hover:mm-bg-transparent-black-300 dark:hover:mm-bg-transparent-white-300"
+                        },
+                        (date == today).then_some("mm-font-bold"),
+                        (!selectable).then_some("mm-opacity-30 mm-cursor-not-allowed"),
+                    );
+
+                    let onclick = {
+                        let onselect = props.onselect.clone();
+                        let focused = focused.clone();
+
+                        move |_| {
+                            focused.set(date);
+                            if selectable {
+                                onselect.emit(date);
+                            }
+                        }
+                    };
+
+                    let onmouseenter = {
+                        let onhover = props.onhover.clone();
+
+                        move |_| onhover.emit(date)
+                    };
+
+                    html! {
+                        <td role="gridcell" aria-selected={endpoint.to_string()} class="mm-p-0 mm-text-center">
+                            <button
+                                type="button"
+                                {class}
+                                data-date={date.format("%Y-%m-%d").to_string()}
+                                tabindex={if date == *focused { "0" } else { "-1" }}
+                                aria-label={date.format("%A, %B %-d, %Y").to_string()}
+                                aria-disabled={(!selectable).to_string()}
+                                aria-current={(date == today).then_some("date")}
+                                {onclick}
+                                {onmouseenter}
+                            >
+                                { date.day() }
+                            </button>
+                        </td>
+                    }
+                })
+                .collect::<Html>();
+
+            html! {
+                <tr>{ days }</tr>
+            }
+        })
+        .collect::<Html>();
+
+    let navigation_classes = "mm-inline-flex mm-items-center mm-justify-center mm-w-8 mm-h-8 mm-rounded-md mm-text-gray-low-100 dark:mm-text-gray-low-200 hover:mm-bg-transparent-black-300 dark:hover:mm-bg-transparent-white-200";
+
+    html! {
+        <div class="mm-flex mm-flex-col mm-gap-2 mm-p-3">
+            <div class="mm-flex mm-items-center mm-justify-between">
+                <button type="button" class={navigation_classes} aria-label="Previous month" onclick={previous_month}>
+                    { Icon::CARET_LEFT }
+                </button>
+                <span class="mm-text-sm mm-font-medium mm-text-gray-low-800 dark:mm-text-gray-high-200" aria-live="polite">
+                    { month.format("%B %Y").to_string() }
+                </span>
+                <button type="button" class={navigation_classes} aria-label="Next month" onclick={next_month}>
+                    { Icon::CARET_RIGHT }
+                </button>
+            </div>
+            <table
+                ref={node}
+                role="grid"
+                aria-label={month.format("%B %Y").to_string()}
+                class="mm-border-collapse"
+                {onkeydown}
+            >
+                <thead>
+                    <tr>{ weekdays }</tr>
+                </thead>
+                <tbody>
+                    { weeks }
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -0,0 +1,158 @@
+use uuid::Uuid;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::choice::{ChoiceLabel, CONTROL_CLASSES};
+use crate::Icon;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    /// Whether the checkbox is checked.
+    #[prop_or_default]
+    pub checked: bool,
+
+    /// Shows a dash instead of the check mark, e.g. for a partially selected list.
+    ///  Takes precedence over `checked` for display.
+    #[prop_or_default]
+    pub indeterminate: bool,
+
+    /// Emits the new checked state.
+    #[prop_or_default]
+    pub onchange: Callback<bool>,
+
+    /// Text next to the checkbox.
+    #[prop_or_default]
+    pub label: AttrValue,
+
+    /// Secondary text under the label.
+    #[prop_or_default]
+    pub description: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub required: bool,
+
+    /// Name of the form field.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    /// Value submitted with the form when checked.
+    #[prop_or(AttrValue::from("on"))]
+    pub value: AttrValue,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Checkbox component
+/// Styled native checkbox with an optional indeterminate state.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::Checkbox;
+///
+/// #[function_component(CheckboxExample)]
+/// fn checkbox_example() -> Html {
+///     let checked = use_state(|| false);
+///     let onchange = {
+///         let checked = checked.clone();
+///         Callback::from(move |value| checked.set(value))
+///     };
+///
+///     html! {
+///         <Checkbox
+///             checked={*checked}
+///             {onchange}
+///             label="Email notifications"
+///             description="Get notified when someone mentions you."
+///             name="notifications"
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Checkbox(props: &Props) -> Html {
+    let id = use_memo((), |_| format!("mm-checkbox-{}", Uuid::new_v4()));
+    let description_id = format!("{id}-description");
+    let node = use_node_ref();
+
+    // `indeterminate` can only be set through the DOM property
+    use_effect_with(props.indeterminate, {
+        let node = node.clone();
+
+        move |indeterminate| {
+            if let Some(input) = node.cast::<HtmlInputElement>() {
+                input.set_indeterminate(*indeterminate);
+            }
+        }
+    });
+
+    let onchange = {
+        let onchange = props.onchange.clone();
+
+        move |event: Event| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+
+            onchange.emit(input.checked());
+        }
+    };
+
+    let mark = if props.indeterminate {
+        Some(Icon::MINUS_BOLD)
+    } else if props.checked {
+        Some(Icon::CHECK_BOLD)
+    } else {
+        None
+    };
+
+    html! {
+        <label
+            for={(*id).clone()}
+            class={classes!("mm-inline-flex", "mm-items-start", "mm-gap-2", "mm-select-none", props.class.clone())}
+        >
+            <span class="mm-relative mm-inline-flex mm-mt-0.5">
+                <input
+                    ref={node}
+                    id={(*id).clone()}
+                    type="checkbox"
+                    class={classes!(
+                        CONTROL_CLASSES,
+                        "mm-w-4",
+                        "mm-h-4",
+                        "mm-rounded",
+                        "mm-border",
+                        "mm-border-gray-high-950",
+                        "dark:mm-border-gray-low-300",
+                        "checked:mm-bg-primary-700",
+                        "checked:mm-border-primary-700",
+                        "dark:checked:mm-bg-primary-500",
+                        "dark:checked:mm-border-primary-500",
+                        "indeterminate:mm-bg-primary-700",
+                        "indeterminate:mm-border-primary-700",
+                        "dark:indeterminate:mm-bg-primary-500",
+                        "dark:indeterminate:mm-border-primary-500",
+                    )}
+                    checked={props.checked}
+                    disabled={props.disabled}
+                    required={props.required}
+                    name={props.name.clone()}
+                    value={props.value.clone()}
+                    aria-checked={props.indeterminate.then_some("mixed")}
+                    aria-describedby={props.description.is_some().then(|| description_id.clone())}
+                    {onchange}
+                />
+                if let Some(mark) = mark {
+                    <span class="mm-pointer-events-none mm-absolute mm-inset-0 mm-flex mm-items-center mm-justify-center mm-text-xs mm-text-white">{ mark }</span>
+                }
+            </span>
+            <ChoiceLabel
+                label={props.label.clone()}
+                description={props.description.clone()}
+                description_id={description_id}
+            />
+        </label>
+    }
+}
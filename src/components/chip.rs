@@ -0,0 +1,44 @@
+use yew::prelude::*;
+
+use crate::Icon;
+
+#[derive(PartialEq, Properties)]
+pub(crate) struct ChipProps {
+    /// Text of the chip.
+    pub label: AttrValue,
+
+    /// Emitted by the remove button.
+    pub onremove: Callback<()>,
+
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Removable value shown inside a field, shared by `Combobox` and `TagInput`.
+#[function_component]
+pub(crate) fn Chip(props: &ChipProps) -> Html {
+    let onclick = {
+        let onremove = props.onremove.clone();
+
+        move |event: MouseEvent| {
+            event.stop_propagation();
+            onremove.emit(());
+        }
+    };
+
+    html! {
+        <span class="mm-inline-flex mm-items-center mm-gap-1 mm-rounded mm-pl-2 mm-pr-1 mm-py-0.5 mm-text-sm mm-bg-transparent-black-300 dark:mm-bg-transparent-white-300 mm-text-gray-low-800 dark:mm-text-gray-high-200">
+            { props.label.clone() }
+            <button
+                type="button"
+                tabindex="-1"
+                aria-label={format!("Remove {}", props.label)}
+                class="mm-inline-flex mm-items-center mm-rounded-full mm-text-gray-low-100 dark:mm-text-gray-low-200 hover:mm-text-gray-low-800 dark:hover:mm-text-gray-high-200"
+                disabled={props.disabled}
+                {onclick}
+            >
+                { Icon::X }
+            </button>
+        </span>
+    }
+}
@@ -0,0 +1,36 @@
+use yew::prelude::*;
+
+/// Focus and disabled states shared by the native inputs of
+///  `Checkbox`, `RadioGroup` and `Switch`.
+pub(crate) const CONTROL_CLASSES: &str = "mm-peer mm-appearance-none mm-shrink-0 mm-cursor-pointer mm-transition-colors mm-duration-125 mm-ease-in-out mm-outline-none focus-visible:mm-ring-2 focus-visible:mm-ring-primary-500 focus-visible:mm-ring-offset-1 disabled:mm-opacity-30 disabled:mm-cursor-not-allowed";
+
+#[derive(PartialEq, Properties)]
+pub(crate) struct ChoiceLabelProps {
+    /// Text next to the control.
+    pub label: AttrValue,
+
+    /// Secondary text under the label.
+    pub description: Option<AttrValue>,
+
+    /// Id of the description, referenced by `aria-describedby` of the control.
+    pub description_id: AttrValue,
+}
+
+/// Label and description of a boolean or choice control.
+#[function_component]
+pub(crate) fn ChoiceLabel(props: &ChoiceLabelProps) -> Html {
+    if props.label.is_empty() && props.description.is_none() {
+        return html! {};
+    }
+
+    html! {
+        <span class="mm-flex mm-flex-col mm-gap-0.5">
+            if !props.label.is_empty() {
+                <span class="mm-text-sm mm-font-medium mm-text-gray-low-800 dark:mm-text-gray-high-200">{ props.label.clone() }</span>
+            }
+            if let Some(description) = &props.description {
+                <span id={props.description_id.clone()} class="mm-text-sm mm-text-gray-low-100 dark:mm-text-gray-low-200">{ description.clone() }</span>
+            }
+        </span>
+    }
+}
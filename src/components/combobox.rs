@@ -0,0 +1,469 @@
+use std::{future::Future, pin::Pin, rc::Rc};
+
+use uuid::Uuid;
+use web_sys::HtmlInputElement;
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::use_click_away;
+
+use super::{
+    chip::Chip,
+    input::{field_classes, Size, Variant},
+    listbox::{option_id, step, Listbox, SelectOption},
+};
+use crate::Icon;
+
+type OptionsFuture<V> = Pin<Box<dyn Future<Output = Vec<SelectOption<V>>>>>;
+
+/// Asynchronous source of options for a [`Combobox`], called with the current query.
+pub struct OptionsLoader<V>(Rc<dyn Fn(String) -> OptionsFuture<V>>);
+
+impl<V> OptionsLoader<V> {
+    pub fn new<F, Fut>(f: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Vec<SelectOption<V>>> + 'static,
+    {
+        Self(Rc::new(move |query| Box::pin(f(query))))
+    }
+}
+
+impl<V> Clone for OptionsLoader<V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<V> PartialEq for OptionsLoader<V> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props<V>
+where
+    V: PartialEq,
+{
+    /// Options to filter by the typed query.
+    ///
+    /// When `loader` is set, they are only used to resolve the labels of the selected values.
+    #[prop_or_default]
+    pub options: Vec<SelectOption<V>>,
+
+    /// Loads the options for the typed query instead of filtering `options`.
+    #[prop_or_default]
+    pub loader: Option<OptionsLoader<V>>,
+
+    /// Currently selected values.
+    #[prop_or_default]
+    pub value: Vec<V>,
+
+    /// Emits the selected values after every change.
+    #[prop_or_default]
+    pub onchange: Callback<Vec<V>>,
+
+    /// Whether more than one option can be selected.
+    #[prop_or_default]
+    pub multiple: bool,
+
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+
+    /// Variant of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Size of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub size: Size,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Combobox component
+/// Searchable picker with type-to-filter, asynchronous options and multi-select chips.
+///
+/// ## Example (asynchronous options)
+/// ```rust
+/// use gloo_net::http::Request;
+/// use yew::prelude::*;
+/// use ui_common::components::{Combobox, ComboboxOptionsLoader, SelectOption};
+///
+/// #[function_component(ComboboxExample)]
+/// fn combobox_example() -> Html {
+///     let users = use_state(Vec::<u64>::new);
+///     let onchange = {
+///         let users = users.clone();
+///         Callback::from(move |value| users.set(value))
+///     };
+///
+///     let loader = use_memo((), |_| {
+///         ComboboxOptionsLoader::new(|query: String| async move {
+///             let users: Vec<(u64, String)> = match Request::get("/api/users")
+///                 .query([("q", query)])
+///                 .send()
+///                 .await
+///             {
+///                 Ok(response) => response.json().await.unwrap_or_default(),
+///                 Err(_) => Vec::new(),
+///             };
+///
+///             users
+///                 .into_iter()
+///                 .map(|(id, name)| SelectOption::new(id, name))
+///                 .collect()
+///         })
+///     });
+///
+///     html! {
+///         <Combobox<u64>
+///             loader={(*loader).clone()}
+///             value={(*users).clone()}
+///             {onchange}
+///             multiple=true
+///             placeholder="Assignees"
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Combobox<V>(props: &Props<V>) -> Html
+where
+    V: Clone + PartialEq + 'static,
+{
+    let id = use_memo((), |_| format!("mm-combobox-{}", Uuid::new_v4()));
+    let listbox_id = format!("{id}-listbox");
+
+    let open = use_state(|| false);
+    let active = use_state(|| None::<usize>);
+    let query = use_state(String::new);
+    // Whether the query was typed, rather than filled in with the label of the selected option,
+    //  which would filter the options down to that one when the list reopens
+    let typed = use_state(|| false);
+    let loaded = use_state(Vec::<SelectOption<V>>::new);
+    let loading = use_state(|| false);
+    // Options which were selected at some point, so chips keep their labels
+    //  after the loader returns a different list
+    let known = use_mut_ref(Vec::<SelectOption<V>>::new);
+    // Only the latest request is allowed to update the list
+    let request = use_mut_ref(|| 0_usize);
+
+    let node = use_node_ref();
+    let input_ref = use_node_ref();
+
+    use_click_away(node.clone(), {
+        let open = open.clone();
+
+        move |_event: Event| {
+            open.set(false);
+        }
+    });
+
+    let filter = if *typed {
+        (*query).clone()
+    } else {
+        String::new()
+    };
+
+    use_effect_with((filter.clone(), *open, props.loader.clone()), {
+        let loaded = loaded.clone();
+        let loading = loading.clone();
+        let request = request.clone();
+
+        move |(query, open, loader)| {
+            if let (true, Some(loader)) = (*open, loader) {
+                let current = {
+                    let mut request = request.borrow_mut();
+                    *request += 1;
+                    *request
+                };
+
+                loading.set(true);
+
+                let future = (loader.0)(query.clone());
+                spawn_local(async move {
+                    let options = future.await;
+
+                    if *request.borrow() == current {
+                        loaded.set(options);
+                        loading.set(false);
+                    }
+                });
+            }
+        }
+    });
+
+    let options = match &props.loader {
+        Some(_) => (*loaded).clone(),
+        None => {
+            let needle = filter.to_lowercase();
+
+            props
+                .options
+                .iter()
+                .filter(|option| option.label.to_lowercase().contains(&needle))
+                .cloned()
+                .collect()
+        }
+    };
+
+    let label_of = |value: &V| {
+        props
+            .options
+            .iter()
+            .chain(options.iter())
+            .chain(known.borrow().iter())
+            .find(|option| &option.value == value)
+            .cloned()
+    };
+    let selected = props.value.iter().filter_map(label_of).collect::<Vec<_>>();
+
+    // A single selection is shown in the field, also when it is set or changed by the parent
+    use_effect_with(props.value.clone(), {
+        let query = query.clone();
+        let typed = typed.clone();
+        let multiple = props.multiple;
+        let label = selected
+            .first()
+            .map(|option| option.label.to_string())
+            .unwrap_or_default();
+
+        move |_| {
+            if !multiple {
+                query.set(label);
+                typed.set(false);
+            }
+        }
+    });
+
+    let toggle = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+        let multiple = props.multiple;
+        let known = known.clone();
+        let query = query.clone();
+        let typed = typed.clone();
+        let open = open.clone();
+
+        Callback::from(move |option: SelectOption<V>| {
+            if option.disabled {
+                return;
+            }
+
+            let mut known = known.borrow_mut();
+            if !known.iter().any(|known| known.value == option.value) {
+                known.push(option.clone());
+            }
+
+            if multiple {
+                let mut value = value.clone();
+                match value.iter().position(|value| value == &option.value) {
+                    Some(index) => {
+                        value.remove(index);
+                    }
+                    None => value.push(option.value),
+                }
+
+                query.set(String::new());
+                onchange.emit(value);
+            } else {
+                query.set(option.label.to_string());
+                typed.set(false);
+                open.set(false);
+                onchange.emit(vec![option.value]);
+            }
+        })
+    };
+
+    let remove = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+
+        Callback::from(move |removed: V| {
+            onchange.emit(
+                value
+                    .iter()
+                    .filter(|value| *value != &removed)
+                    .cloned()
+                    .collect(),
+            );
+        })
+    };
+
+    let onselect = {
+        let toggle = toggle.clone();
+        let options = options.clone();
+
+        Callback::from(move |index: usize| {
+            if let Some(option) = options.get(index) {
+                toggle.emit(option.clone());
+            }
+        })
+    };
+
+    let onhover = {
+        let active = active.clone();
+
+        Callback::from(move |index| active.set(Some(index)))
+    };
+
+    let oninput = {
+        let query = query.clone();
+        let typed = typed.clone();
+        let open = open.clone();
+        let active = active.clone();
+
+        move |event: InputEvent| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+
+            query.set(input.value());
+            typed.set(true);
+            active.set(None);
+            open.set(true);
+        }
+    };
+
+    let onfocus = {
+        let open = open.clone();
+        let disabled = props.disabled;
+
+        move |_| {
+            if !disabled {
+                open.set(true);
+            }
+        }
+    };
+
+    let onkeydown = {
+        let open = open.clone();
+        let active = active.clone();
+        let query = query.clone();
+        let options = options.clone();
+        let toggle = toggle.clone();
+        let remove = remove.clone();
+        let value = props.value.clone();
+        let multiple = props.multiple;
+
+        move |event: KeyboardEvent| {
+            let key = event.key();
+            match key.as_str() {
+                "ArrowDown" | "ArrowUp" => {
+                    event.prevent_default();
+                    open.set(true);
+                    active.set(step(&options, *active, key == "ArrowDown"));
+                }
+                "Home" | "End" if *open => {
+                    event.prevent_default();
+                    active.set(step(&options, None, key == "Home"));
+                }
+                "Enter" if *open => {
+                    event.prevent_default();
+                    if let Some(option) = (*active).and_then(|index| options.get(index)) {
+                        toggle.emit(option.clone());
+                    }
+                }
+                "Escape" if *open => {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    open.set(false);
+                }
+                "Backspace" if multiple && query.is_empty() => {
+                    if let Some(last) = value.last() {
+                        remove.emit(last.clone());
+                    }
+                }
+                "Tab" => open.set(false),
+                _ => {}
+            }
+        }
+    };
+
+    let focus_input = {
+        let input_ref = input_ref.clone();
+
+        move |_| {
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                let _ = input.focus();
+            }
+        }
+    };
+
+    let chips = if props.multiple {
+        selected
+            .iter()
+            .map(|option| {
+                let onremove = {
+                    let remove = remove.clone();
+                    let value = option.value.clone();
+
+                    Callback::from(move |_| remove.emit(value.clone()))
+                };
+
+                html! {
+                    <Chip label={option.label.clone()} {onremove} disabled={props.disabled} />
+                }
+            })
+            .collect::<Html>()
+    } else {
+        html! {}
+    };
+
+    let class = classes!(
+        field_classes(&props.variant, &props.size),
+        "mm-flex",
+        "mm-flex-wrap",
+        "mm-items-center",
+        "mm-gap-1",
+        "mm-cursor-text",
+        "focus-within:mm-border-transparent-black-800",
+        "dark:focus-within:mm-border-transparent-white-800",
+        props
+            .disabled
+            .then_some("mm-bg-gray-high-300 dark:mm-bg-gray-low-900 mm-cursor-not-allowed"),
+        props.class.clone(),
+    );
+
+    html! {
+        <div class="mm-relative mm-w-full" ref={node}>
+            <div {class} onclick={focus_input}>
+                { chips }
+                <input
+                    ref={input_ref}
+                    id={(*id).clone()}
+                    class="mm-flex-1 mm-min-w-16 mm-bg-transparent mm-outline-none placeholder:mm-text-gray-high-900 placeholder:dark:mm-text-gray-low-400 disabled:mm-cursor-not-allowed"
+                    type="text"
+                    role="combobox"
+                    autocomplete="off"
+                    aria-autocomplete="list"
+                    aria-expanded={open.to_string()}
+                    aria-controls={listbox_id.clone()}
+                    aria-activedescendant={(*open).then(|| (*active).map(|index| option_id(&listbox_id, index))).flatten()}
+                    placeholder={(selected.is_empty() || props.multiple).then(|| props.placeholder.clone())}
+                    value={(*query).clone()}
+                    disabled={props.disabled}
+                    {oninput}
+                    {onfocus}
+                    {onkeydown}
+                />
+                <span class="mm-inline-flex mm-items-center mm-shrink-0 mm-text-gray-low-100 dark:mm-text-gray-low-200">{ Icon::MAGNIFYING_GLASS }</span>
+            </div>
+            if *open {
+                <Listbox<V>
+                    id={listbox_id}
+                    {options}
+                    selected={props.value.clone()}
+                    active={*active}
+                    multiple={props.multiple}
+                    loading={*loading}
+                    {onselect}
+                    {onhover}
+                />
+            }
+        </div>
+    }
+}
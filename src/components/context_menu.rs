@@ -0,0 +1,308 @@
+use std::rc::Rc;
+
+use gloo_utils::{body, document};
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
+use yew::prelude::*;
+
+use super::{
+    dropdown_menu::{in_menu, MenuItem, MenuList},
+    floating::{compute, Align, Placement, Rect, Side},
+    overlay::{use_overlay, OverlayOptions},
+};
+
+#[derive(Clone)]
+pub struct UseContextMenuHandle {
+    /// Whether the menu is open.
+    pub open: bool,
+
+    /// Opens the menu at the cursor, to attach to `oncontextmenu`.
+    pub oncontextmenu: Callback<MouseEvent>,
+
+    /// Opens the menu below the focused element on Shift+F10 or the Menu key,
+    ///  to attach to `onkeydown`.
+    pub onkeydown: Callback<KeyboardEvent>,
+
+    /// Closes the menu.
+    pub close: Callback<()>,
+
+    /// The menu, rendered into `body`, it can be placed anywhere in the tree.
+    pub menu: Html,
+}
+
+/// # use_context_menu hook
+/// Custom context menu for any element, e.g. a canvas. The handlers of the handle
+///  have to be attached to the element, and its `menu` rendered next to it.
+///  [`ContextMenu`] does both for its children.
+///
+/// The menu opens at the cursor, moved back inside the viewport when it would overflow.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{use_context_menu, MenuItem};
+///
+/// #[function_component(CanvasExample)]
+/// fn canvas_example() -> Html {
+///     let context_menu = use_context_menu(
+///         vec![
+///             MenuItem::action("Add shape", Callback::from(|_| ())),
+///             MenuItem::action("Paste", Callback::from(|_| ())).with_disabled(true),
+///         ],
+///         Some("Canvas".into()),
+///     );
+///
+///     html! {
+///         <>
+///             <canvas
+///                 tabindex="0"
+///                 oncontextmenu={context_menu.oncontextmenu.clone()}
+///                 onkeydown={context_menu.onkeydown.clone()}
+///             />
+///             { context_menu.menu.clone() }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_context_menu(items: Vec<MenuItem>, label: Option<AttrValue>) -> UseContextMenuHandle {
+    let id = use_memo((), |_| format!("mm-context-menu-{}", Uuid::new_v4()));
+    // Requested point, in viewport coordinates
+    let point = use_state(|| None::<(f64, f64)>);
+    // Point moved inside the viewport once the menu size is known
+    let position = use_state_eq(|| None::<(f64, f64)>);
+    let menu_ref = use_node_ref();
+    // Element to give the focus back to
+    let previous = use_mut_ref(|| None::<HtmlElement>);
+
+    let open_at = {
+        let point = point.clone();
+        let position = position.clone();
+        let previous = previous.clone();
+
+        Rc::new(move |x: f64, y: f64| {
+            if previous.borrow().is_none() {
+                *previous.borrow_mut() = document()
+                    .active_element()
+                    .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+            }
+            // The position of another point would be drawn for a frame, it is computed again
+            if *point != Some((x, y)) {
+                position.set(None);
+            }
+            point.set(Some((x, y)));
+        })
+    };
+
+    // Closes the menu and gives the focus back
+    let close = {
+        let point = point.clone();
+        let position = position.clone();
+        let previous = previous.clone();
+
+        Callback::from(move |_| {
+            point.set(None);
+            position.set(None);
+            if let Some(previous) = previous.borrow_mut().take() {
+                let _ = previous.focus();
+            }
+        })
+    };
+
+    let oncontextmenu = {
+        let id = id.clone();
+        let open_at = open_at.clone();
+
+        Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+
+            // Events bubble from the portal too, a right click on the menu keeps it in place
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            if !in_menu(target.as_ref(), &id) {
+                open_at(event.client_x() as f64, event.client_y() as f64);
+            }
+        })
+    };
+
+    let onkeydown = Callback::from({
+        let id = id.clone();
+
+        move |event: KeyboardEvent| {
+            let requested =
+                event.key() == "ContextMenu" || (event.shift_key() && event.key() == "F10");
+            if !requested {
+                return;
+            }
+            event.prevent_default();
+
+            if let Some(target) = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .filter(|target| !in_menu(Some(target), &id))
+            {
+                let rect = target.get_bounding_client_rect();
+                open_at(rect.left(), rect.bottom());
+            }
+        }
+    });
+
+    use_effect_with(*point, {
+        let menu_ref = menu_ref.clone();
+        let position = position.clone();
+
+        move |point| {
+            if let (Some((x, y)), Some(menu)) = (point, menu_ref.cast::<Element>()) {
+                let size = menu.get_bounding_client_rect();
+                let placed = compute(
+                    Rect {
+                        left: *x,
+                        top: *y,
+                        width: 0.0,
+                        height: 0.0,
+                    },
+                    (size.width(), size.height()),
+                    Rect::viewport(),
+                    Placement {
+                        side: Some(Side::Right),
+                        align: Align::Start,
+                        offset: 0.0,
+                        padding: 4.0,
+                        arrow_padding: 0.0,
+                    },
+                );
+
+                position.set(Some((placed.left, placed.top)));
+            }
+        }
+    });
+
+    // Only the topmost overlay reacts to clicks away, Escape is handled by the menu itself
+    let z_index = use_overlay(
+        point.is_some(),
+        OverlayOptions {
+            lock_scroll: false,
+            on_escape: Some(close.clone()),
+            on_click_away: Some({
+                let id = id.clone();
+                let point = point.clone();
+                let previous = previous.clone();
+
+                Callback::from(move |event: Event| {
+                    let target = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<Node>().ok());
+
+                    if !in_menu(target.as_ref(), &id) {
+                        // The click moves the focus itself
+                        previous.borrow_mut().take();
+                        point.set(None);
+                    }
+                })
+            }),
+        },
+    );
+
+    let menu = match (*point, *position) {
+        (Some(point), position) => {
+            let (left, top) = position.unwrap_or(point);
+
+            create_portal(
+                html! {
+                    <MenuList
+                        id={AttrValue::from((*id).clone())}
+                        root={AttrValue::from((*id).clone())}
+                        {items}
+                        {label}
+                        onclose={close.clone()}
+                        {top}
+                        {left}
+                        {z_index}
+                        node_ref={menu_ref}
+                    />
+                },
+                body().into(),
+            )
+        }
+        (None, _) => html! {},
+    };
+
+    UseContextMenuHandle {
+        open: point.is_some(),
+        oncontextmenu,
+        onkeydown,
+        close,
+        menu,
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct ContextMenuProps {
+    /// Items of the menu
+    pub items: Vec<MenuItem>,
+
+    /// Elements on which a right click opens the menu
+    pub children: Children,
+
+    /// Accessible name of the menu
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// Additional classes on top of the wrapping element
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # ContextMenu component
+/// Replaces the browser context menu of its children with a menu of
+///  [`MenuItem`]s, the same ones as a [`DropdownMenu`](super::DropdownMenu).
+///
+/// A right click opens the menu at the cursor, Shift+F10 or the Menu key
+///  opens it below the focused element.
+///
+/// See [ContextMenuProps](ContextMenuProps) for more details.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::{components::{ContextMenu, MenuItem}, Icon};
+///
+/// #[function_component(ContextMenuExample)]
+/// fn context_menu_example() -> Html {
+///     let items = vec![
+///         MenuItem::action("Copy", Callback::from(|_| ())).with_icon(Icon::COPY),
+///         MenuItem::separator(),
+///         MenuItem::action("Delete", Callback::from(|_| ())).with_icon(Icon::TRASH),
+///     ];
+///
+///     html! {
+///         <ContextMenu {items} label="Row actions">
+///             <div tabindex="0">{"Right click me"}</div>
+///         </ContextMenu>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ContextMenu(
+    ContextMenuProps {
+        items,
+        children,
+        label,
+        class,
+    }: &ContextMenuProps,
+) -> Html {
+    let context_menu = use_context_menu(items.clone(), label.clone());
+
+    html! {
+        <div
+            class={class.clone()}
+            oncontextmenu={context_menu.oncontextmenu}
+            onkeydown={context_menu.onkeydown}
+        >
+            { for children.iter() }
+            { context_menu.menu }
+        </div>
+    }
+}
@@ -0,0 +1,302 @@
+use chrono::{NaiveDate, Weekday};
+use uuid::Uuid;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_hooks::use_click_away;
+
+use super::{
+    calendar::{
+        browser_locale, is_selectable, month_first, parse_date, today, week_start_for_locale,
+        Calendar, POPUP_CLASSES,
+    },
+    input::{field_classes, Size, Variant},
+};
+use crate::Icon;
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// Currently selected date.
+    #[prop_or_default]
+    pub value: Option<NaiveDate>,
+
+    /// Emits the chosen date, or `None` when the field is cleared.
+    #[prop_or_default]
+    pub onchange: Callback<Option<NaiveDate>>,
+
+    /// Earliest date which can be chosen.
+    #[prop_or_default]
+    pub min: Option<NaiveDate>,
+
+    /// Latest date which can be chosen.
+    #[prop_or_default]
+    pub max: Option<NaiveDate>,
+
+    /// Returns `true` for the dates which can't be chosen.
+    #[prop_or_default]
+    pub disabled_dates: Option<Callback<NaiveDate, bool>>,
+
+    /// First day of the week, taken from the browser locale when not set.
+    #[prop_or_default]
+    pub week_start: Option<Weekday>,
+
+    /// `chrono` format of the displayed date.
+    #[prop_or(AttrValue::from("%Y-%m-%d"))]
+    pub format: AttrValue,
+
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+
+    /// Name of the form field, submitted as an ISO 8601 date.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    /// Variant of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Size of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub size: Size,
+
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub required: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # DatePicker component
+/// Text field with a calendar popup. Typed dates are parsed leniently
+///  (see [`parse_date`](super::parse_date)) when the field loses focus or on Enter.
+///
+/// ## Example
+/// ```rust
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use yew::prelude::*;
+/// use ui_common::components::DatePicker;
+///
+/// #[function_component(DatePickerExample)]
+/// fn date_picker_example() -> Html {
+///     let date = use_state(|| None);
+///     let onchange = {
+///         let date = date.clone();
+///         Callback::from(move |value| date.set(value))
+///     };
+///
+///     html! {
+///         <DatePicker
+///             value={*date}
+///             {onchange}
+///             min={NaiveDate::from_ymd_opt(2024, 1, 1)}
+///             disabled_dates={Callback::from(|date: NaiveDate| date.weekday() == Weekday::Sun)}
+///             placeholder="Due date"
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn DatePicker(props: &Props) -> Html {
+    let id = use_memo((), |_| format!("mm-date-picker-{}", Uuid::new_v4()));
+    let dialog_id = format!("{id}-dialog");
+    let locale = use_memo((), |_| browser_locale());
+    let week_start = props
+        .week_start
+        .unwrap_or_else(|| week_start_for_locale(&locale));
+
+    let open = use_state(|| false);
+    let text = use_state(String::new);
+    let node = use_node_ref();
+
+    use_click_away(node.clone(), {
+        let open = open.clone();
+
+        move |_event: Event| {
+            open.set(false);
+        }
+    });
+
+    // Show the formatted value whenever it changes from the outside
+    use_effect_with((props.value, props.format.clone()), {
+        let text = text.clone();
+
+        move |(value, format)| {
+            text.set(
+                value
+                    .map(|value| value.format(format).to_string())
+                    .unwrap_or_default(),
+            );
+        }
+    });
+
+    let select = {
+        let open = open.clone();
+        let onchange = props.onchange.clone();
+
+        Callback::from(move |date: NaiveDate| {
+            open.set(false);
+            onchange.emit(Some(date));
+        })
+    };
+
+    let commit = {
+        let text = text.clone();
+        let value = props.value;
+        let format = props.format.clone();
+        let onchange = props.onchange.clone();
+        let (min, max, disabled) = (props.min, props.max, props.disabled_dates.clone());
+        let month_first = month_first(&locale);
+
+        move || {
+            if text.trim().is_empty() {
+                if value.is_some() {
+                    onchange.emit(None);
+                }
+                return;
+            }
+
+            match parse_date(&text, today(), month_first)
+                .filter(|date| is_selectable(*date, min, max, &disabled))
+            {
+                Some(date) if Some(date) != value => onchange.emit(Some(date)),
+                // Restore the current value when the text can't be parsed
+                _ => text.set(
+                    value
+                        .map(|value| value.format(&format).to_string())
+                        .unwrap_or_default(),
+                ),
+            }
+        }
+    };
+
+    let oninput = {
+        let text = text.clone();
+
+        move |event: InputEvent| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+
+            text.set(input.value());
+        }
+    };
+
+    let onblur = {
+        let commit = commit.clone();
+
+        move |_| commit()
+    };
+
+    let onkeydown = {
+        let open = open.clone();
+
+        move |event: KeyboardEvent| match event.key().as_str() {
+            "Enter" => {
+                event.prevent_default();
+                commit();
+                open.set(false);
+            }
+            "ArrowDown" if event.alt_key() => open.set(true),
+            "Escape" if *open => {
+                event.stop_propagation();
+                open.set(false);
+            }
+            _ => {}
+        }
+    };
+
+    let toggle = {
+        let open = open.clone();
+        let disabled = props.disabled;
+
+        move |_| {
+            if !disabled {
+                open.set(!*open);
+            }
+        }
+    };
+
+    let onpopupkeydown = {
+        let open = open.clone();
+
+        move |event: KeyboardEvent| {
+            if event.key() == "Escape" {
+                event.stop_propagation();
+                open.set(false);
+            }
+        }
+    };
+
+    let class = classes!(
+        field_classes(&props.variant, &props.size),
+        "mm-flex",
+        "mm-items-center",
+        "mm-gap-2",
+        "focus-within:mm-border-transparent-black-800",
+        "dark:focus-within:mm-border-transparent-white-800",
+        props
+            .disabled
+            .then_some("mm-bg-gray-high-300 dark:mm-bg-gray-low-900 mm-cursor-not-allowed"),
+        props.class.clone(),
+    );
+
+    html! {
+        <div class="mm-relative mm-w-full" ref={node}>
+            <div {class}>
+                <input
+                    id={(*id).clone()}
+                    class="mm-flex-1 mm-min-w-0 mm-bg-transparent mm-outline-none placeholder:mm-text-gray-high-900 placeholder:dark:mm-text-gray-low-400 disabled:mm-cursor-not-allowed"
+                    type="text"
+                    autocomplete="off"
+                    placeholder={props.placeholder.clone()}
+                    value={(*text).clone()}
+                    disabled={props.disabled}
+                    required={props.required}
+                    {oninput}
+                    {onblur}
+                    {onkeydown}
+                />
+                <button
+                    type="button"
+                    class="mm-inline-flex mm-items-center mm-shrink-0 mm-text-xl mm-text-gray-low-100 dark:mm-text-gray-low-200 hover:mm-text-gray-low-800 dark:hover:mm-text-gray-high-200 disabled:mm-cursor-not-allowed"
+                    aria-label="Choose date"
+                    aria-haspopup="dialog"
+                    aria-expanded={open.to_string()}
+                    aria-controls={dialog_id.clone()}
+                    disabled={props.disabled}
+                    onclick={toggle}
+                >
+                    { Icon::CALENDAR_BLANK }
+                </button>
+            </div>
+            if let Some(name) = &props.name {
+                <input
+                    type="hidden"
+                    name={name.clone()}
+                    value={props.value.map(|value| value.format("%Y-%m-%d").to_string()).unwrap_or_default()}
+                />
+            }
+            if *open {
+                <div
+                    id={dialog_id}
+                    class={POPUP_CLASSES}
+                    role="dialog"
+                    aria-modal="false"
+                    aria-label="Choose date"
+                    onkeydown={onpopupkeydown}
+                >
+                    <Calendar
+                        initial={props.value.unwrap_or_else(today)}
+                        start={props.value}
+                        end={props.value}
+                        min={props.min}
+                        max={props.max}
+                        disabled={props.disabled_dates.clone()}
+                        {week_start}
+                        onselect={select}
+                    />
+                </div>
+            }
+        </div>
+    }
+}
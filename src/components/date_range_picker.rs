@@ -0,0 +1,480 @@
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use uuid::Uuid;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_hooks::use_click_away;
+
+use super::{
+    calendar::{
+        browser_locale, is_selectable, month_first, parse_date, today, week_start_for_locale,
+        Calendar, POPUP_CLASSES,
+    },
+    input::{field_classes, Size, Variant},
+};
+use crate::Icon;
+
+/// Inclusive range of dates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// Creates a range, swapping the dates when `end` is before `start`.
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Self {
+        if end < start {
+            Self {
+                start: end,
+                end: start,
+            }
+        } else {
+            Self { start, end }
+        }
+    }
+
+    /// Range of the last `days` days, including `today`.
+    pub fn last_days(today: NaiveDate, days: u64) -> Self {
+        Self::new(today - Days::new(days.saturating_sub(1)), today)
+    }
+}
+
+/// Shortcut for a commonly used range, such as "Last 7 days".
+#[derive(Clone, Debug, PartialEq)]
+pub struct DateRangePreset {
+    pub label: AttrValue,
+
+    /// Computes the range for the given day (today).
+    pub range: Callback<NaiveDate, DateRange>,
+}
+
+impl DateRangePreset {
+    pub fn new<F>(label: impl Into<AttrValue>, range: F) -> Self
+    where
+        F: Fn(NaiveDate) -> DateRange + 'static,
+    {
+        Self {
+            label: label.into(),
+            range: Callback::from(range),
+        }
+    }
+
+    /// Today, yesterday, last 7 and 30 days, this and last month.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new("Today", |today| DateRange::new(today, today)),
+            Self::new("Yesterday", |today| {
+                let yesterday = today - Days::new(1);
+                DateRange::new(yesterday, yesterday)
+            }),
+            Self::new("Last 7 days", |today| DateRange::last_days(today, 7)),
+            Self::new("Last 30 days", |today| DateRange::last_days(today, 30)),
+            Self::new("This month", |today| {
+                DateRange::new(today.with_day(1).unwrap_or(today), today)
+            }),
+            Self::new("Last month", |today| {
+                let first = today.with_day(1).unwrap_or(today);
+                let start = first - Months::new(1);
+                DateRange::new(start, first - Days::new(1))
+            }),
+        ]
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// Currently selected range.
+    #[prop_or_default]
+    pub value: Option<DateRange>,
+
+    /// Emits the chosen range, or `None` when the field is cleared.
+    #[prop_or_default]
+    pub onchange: Callback<Option<DateRange>>,
+
+    /// Shortcuts shown next to the calendar.
+    #[prop_or_else(DateRangePreset::defaults)]
+    pub presets: Vec<DateRangePreset>,
+
+    /// Earliest date which can be chosen.
+    #[prop_or_default]
+    pub min: Option<NaiveDate>,
+
+    /// Latest date which can be chosen.
+    #[prop_or_default]
+    pub max: Option<NaiveDate>,
+
+    /// Returns `true` for the dates which can't be chosen.
+    #[prop_or_default]
+    pub disabled_dates: Option<Callback<NaiveDate, bool>>,
+
+    /// First day of the week, taken from the browser locale when not set.
+    #[prop_or_default]
+    pub week_start: Option<Weekday>,
+
+    /// `chrono` format of the displayed dates.
+    #[prop_or(AttrValue::from("%Y-%m-%d"))]
+    pub format: AttrValue,
+
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+
+    /// Name prefix of the form fields, submitted as `{name}_start` and `{name}_end`.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    /// Variant of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Size of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub size: Size,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Separator between the dates of a range, also accepted when typing.
+const SEPARATOR: &str = " – ";
+
+fn format_range(range: &Option<DateRange>, format: &str) -> String {
+    range
+        .map(|range| {
+            format!(
+                "{}{SEPARATOR}{}",
+                range.start.format(format),
+                range.end.format(format)
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// # DateRangePicker component
+/// Text field with a calendar and presets such as "Last 7 days".
+///
+/// The first click in the calendar picks the start of the range, the second one the end.
+///  Typed ranges such as `2024-03-01 - 2024-03-14` are parsed leniently.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{DateRange, DateRangePicker};
+///
+/// #[function_component(DateRangePickerExample)]
+/// fn date_range_picker_example() -> Html {
+///     let range = use_state(|| None::<DateRange>);
+///     let onchange = {
+///         let range = range.clone();
+///         Callback::from(move |value| range.set(value))
+///     };
+///
+///     html! {
+///         <DateRangePicker value={*range} {onchange} placeholder="Period" />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn DateRangePicker(props: &Props) -> Html {
+    let id = use_memo((), |_| format!("mm-date-range-picker-{}", Uuid::new_v4()));
+    let dialog_id = format!("{id}-dialog");
+    let locale = use_memo((), |_| browser_locale());
+    let week_start = props
+        .week_start
+        .unwrap_or_else(|| week_start_for_locale(&locale));
+
+    let open = use_state(|| false);
+    let text = use_state(String::new);
+    // Start picked with the first click, waiting for the end
+    let pending = use_state(|| None::<NaiveDate>);
+    let hovered = use_state(|| None::<NaiveDate>);
+    let node = use_node_ref();
+
+    use_click_away(node.clone(), {
+        let open = open.clone();
+        let pending = pending.clone();
+
+        move |_event: Event| {
+            open.set(false);
+            pending.set(None);
+        }
+    });
+
+    use_effect_with((props.value, props.format.clone()), {
+        let text = text.clone();
+
+        move |(value, format)| text.set(format_range(value, format))
+    });
+
+    let choose = {
+        let open = open.clone();
+        let pending = pending.clone();
+        let onchange = props.onchange.clone();
+
+        Callback::from(move |range: DateRange| {
+            pending.set(None);
+            open.set(false);
+            onchange.emit(Some(range));
+        })
+    };
+
+    let onselect = {
+        let pending = pending.clone();
+        let choose = choose.clone();
+
+        Callback::from(move |date: NaiveDate| match *pending {
+            Some(start) => choose.emit(DateRange::new(start, date)),
+            None => pending.set(Some(date)),
+        })
+    };
+
+    let onhover = {
+        let hovered = hovered.clone();
+
+        Callback::from(move |date| hovered.set(Some(date)))
+    };
+
+    let commit = {
+        let text = text.clone();
+        let value = props.value;
+        let format = props.format.clone();
+        let onchange = props.onchange.clone();
+        let (min, max, disabled) = (props.min, props.max, props.disabled_dates.clone());
+        let month_first = month_first(&locale);
+
+        move || {
+            if text.trim().is_empty() {
+                if value.is_some() {
+                    onchange.emit(None);
+                }
+                return;
+            }
+
+            let today = today();
+            let parse = |date: &str| {
+                parse_date(date, today, month_first)
+                    .filter(|date| is_selectable(*date, min, max, &disabled))
+            };
+
+            // Dates may contain `-` themselves, so try every split around the separators
+            let range = text
+                .match_indices(['–', '-', '~'])
+                .find_map(|(index, separator)| {
+                    let (start, end) = text.split_at(index);
+                    Some(DateRange::new(
+                        parse(start)?,
+                        parse(&end[separator.len()..])?,
+                    ))
+                })
+                .or_else(|| {
+                    text.split_once(" to ")
+                        .and_then(|(start, end)| Some(DateRange::new(parse(start)?, parse(end)?)))
+                });
+
+            match range {
+                Some(range) if Some(range) != value => onchange.emit(Some(range)),
+                // Restore the current value when the text can't be parsed
+                _ => text.set(format_range(&value, &format)),
+            }
+        }
+    };
+
+    let oninput = {
+        let text = text.clone();
+
+        move |event: InputEvent| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+
+            text.set(input.value());
+        }
+    };
+
+    let onblur = {
+        let commit = commit.clone();
+
+        move |_| commit()
+    };
+
+    let onkeydown = {
+        let open = open.clone();
+
+        move |event: KeyboardEvent| match event.key().as_str() {
+            "Enter" => {
+                event.prevent_default();
+                commit();
+                open.set(false);
+            }
+            "ArrowDown" if event.alt_key() => open.set(true),
+            "Escape" if *open => {
+                event.stop_propagation();
+                open.set(false);
+            }
+            _ => {}
+        }
+    };
+
+    let toggle = {
+        let open = open.clone();
+        let pending = pending.clone();
+        let disabled = props.disabled;
+
+        move |_| {
+            if !disabled {
+                pending.set(None);
+                open.set(!*open);
+            }
+        }
+    };
+
+    let onpopupkeydown = {
+        let open = open.clone();
+        let pending = pending.clone();
+
+        move |event: KeyboardEvent| {
+            if event.key() == "Escape" {
+                event.stop_propagation();
+                pending.set(None);
+                open.set(false);
+            }
+        }
+    };
+
+    let today = today();
+    let presets = props
+        .presets
+        .iter()
+        .map(|preset| {
+            let range = preset.range.emit(today);
+            let active = props.value == Some(range);
+
+            let onclick = {
+                let choose = choose.clone();
+
+                move |_| choose.emit(range)
+            };
+
+            html! {
+                <li>
+                    <button
+                        type="button"
+                        class={classes!(
+                            "mm-w-full",
+                            "mm-text-left",
+                            "mm-whitespace-nowrap",
+                            "mm-py-2",
+                            "mm-px-3",
+                            "mm-rounded",
+                            "mm-text-sm",
+                            "mm-transition-colors",
+                            "mm-duration-125",
+                            "hover:mm-bg-transparent-black-300",
+                            "dark:hover:mm-bg-transparent-white-300",
+                            if active {
+                                "mm-font-medium mm-text-primary-700 dark:mm-text-primary-500"
+                            } else {
+                                "mm-text-gray-low-400 dark:mm-text-gray-high-700"
+                            },
+                        )}
+                        aria-pressed={active.to_string()}
+                        {onclick}
+                    >
+                        { preset.label.clone() }
+                    </button>
+                </li>
+            }
+        })
+        .collect::<Html>();
+
+    // While picking, preview the range up to the hovered date
+    let (start, end) = match (*pending, *hovered, props.value) {
+        (Some(start), hovered, _) => (Some(start), hovered.or(Some(start))),
+        (None, _, Some(range)) => (Some(range.start), Some(range.end)),
+        (None, _, None) => (None, None),
+    };
+
+    let class = classes!(
+        field_classes(&props.variant, &props.size),
+        "mm-flex",
+        "mm-items-center",
+        "mm-gap-2",
+        "focus-within:mm-border-transparent-black-800",
+        "dark:focus-within:mm-border-transparent-white-800",
+        props
+            .disabled
+            .then_some("mm-bg-gray-high-300 dark:mm-bg-gray-low-900 mm-cursor-not-allowed"),
+        props.class.clone(),
+    );
+
+    html! {
+        <div class="mm-relative mm-w-full" ref={node}>
+            <div {class}>
+                <input
+                    id={(*id).clone()}
+                    class="mm-flex-1 mm-min-w-0 mm-bg-transparent mm-outline-none placeholder:mm-text-gray-high-900 placeholder:dark:mm-text-gray-low-400 disabled:mm-cursor-not-allowed"
+                    type="text"
+                    autocomplete="off"
+                    placeholder={props.placeholder.clone()}
+                    value={(*text).clone()}
+                    disabled={props.disabled}
+                    {oninput}
+                    {onblur}
+                    {onkeydown}
+                />
+                <button
+                    type="button"
+                    class="mm-inline-flex mm-items-center mm-shrink-0 mm-text-xl mm-text-gray-low-100 dark:mm-text-gray-low-200 hover:mm-text-gray-low-800 dark:hover:mm-text-gray-high-200 disabled:mm-cursor-not-allowed"
+                    aria-label="Choose dates"
+                    aria-haspopup="dialog"
+                    aria-expanded={open.to_string()}
+                    aria-controls={dialog_id.clone()}
+                    disabled={props.disabled}
+                    onclick={toggle}
+                >
+                    { Icon::CALENDAR_BLANK }
+                </button>
+            </div>
+            if let Some(name) = &props.name {
+                <input
+                    type="hidden"
+                    name={format!("{name}_start")}
+                    value={props.value.map(|range| range.start.format("%Y-%m-%d").to_string()).unwrap_or_default()}
+                />
+                <input
+                    type="hidden"
+                    name={format!("{name}_end")}
+                    value={props.value.map(|range| range.end.format("%Y-%m-%d").to_string()).unwrap_or_default()}
+                />
+            }
+            if *open {
+                <div
+                    id={dialog_id}
+                    class={POPUP_CLASSES}
+                    role="dialog"
+                    aria-modal="false"
+                    aria-label="Choose dates"
+                    onkeydown={onpopupkeydown}
+                >
+                    if !props.presets.is_empty() {
+                        <ul class="mm-flex mm-flex-col mm-gap-0.5 mm-p-2 mm-border-r mm-border-transparent-black-400 dark:mm-border-transparent-white-400">
+                            { presets }
+                        </ul>
+                    }
+                    <Calendar
+                        initial={props.value.map_or(today, |range| range.start)}
+                        {start}
+                        {end}
+                        min={props.min}
+                        max={props.max}
+                        disabled={props.disabled_dates.clone()}
+                        {week_start}
+                        {onselect}
+                        {onhover}
+                    />
+                </div>
+            }
+        </div>
+    }
+}
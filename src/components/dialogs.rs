@@ -0,0 +1,421 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use futures::channel::oneshot;
+use yew::prelude::*;
+
+use super::{
+    button::{Button, Color, NoRoute},
+    input::Input,
+    modal::{Modal, ModalActions, ModalBody, ModalTitle},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfirmOptions {
+    pub title: AttrValue,
+    pub body: Html,
+
+    /// Label of the button which confirms.
+    pub confirm_label: AttrValue,
+
+    /// Label of the button which cancels.
+    pub cancel_label: AttrValue,
+
+    /// Shows the confirm button in the danger color, for destructive actions.
+    pub danger: bool,
+}
+
+impl Default for ConfirmOptions {
+    fn default() -> Self {
+        Self {
+            title: AttrValue::default(),
+            body: Html::default(),
+            confirm_label: "Confirm".into(),
+            cancel_label: "Cancel".into(),
+            danger: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertOptions {
+    pub title: AttrValue,
+    pub body: Html,
+
+    /// Label of the button which closes the alert.
+    pub button_label: AttrValue,
+}
+
+impl Default for AlertOptions {
+    fn default() -> Self {
+        Self {
+            title: AttrValue::default(),
+            body: Html::default(),
+            button_label: "OK".into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PromptOptions {
+    pub title: AttrValue,
+    pub body: Html,
+
+    /// Initial text of the field.
+    pub value: String,
+
+    pub placeholder: String,
+
+    /// Label of the button which confirms.
+    pub confirm_label: AttrValue,
+
+    /// Label of the button which cancels.
+    pub cancel_label: AttrValue,
+}
+
+impl Default for PromptOptions {
+    fn default() -> Self {
+        Self {
+            title: AttrValue::default(),
+            body: Html::default(),
+            value: String::new(),
+            placeholder: String::new(),
+            confirm_label: "OK".into(),
+            cancel_label: "Cancel".into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum DialogKind {
+    Confirm(ConfirmOptions),
+    Alert(AlertOptions),
+    Prompt(PromptOptions),
+}
+
+/// Answer of a dialog: `None` when cancelled, the text of the field for a prompt.
+type Answer = Option<String>;
+
+/// Dialog waiting to be shown or answered.
+#[derive(Clone)]
+struct Request {
+    id: usize,
+    kind: DialogKind,
+    sender: Rc<RefCell<Option<oneshot::Sender<Answer>>>>,
+    /// Answered, the dialog stays until its closing transition ends.
+    answered: bool,
+}
+
+impl PartialEq for Request {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Request {
+    fn answer(&self, answer: Answer) {
+        if let Some(sender) = self.sender.borrow_mut().take() {
+            let _ = sender.send(answer);
+        }
+    }
+}
+
+enum Action {
+    Push(Request),
+    Answer(usize),
+    Pop(usize),
+}
+
+#[derive(Default, PartialEq)]
+struct Queue {
+    requests: VecDeque<Request>,
+    next_id: usize,
+}
+
+impl Reducible for Queue {
+    type Action = Action;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut requests = self.requests.clone();
+        let mut next_id = self.next_id;
+
+        match action {
+            Action::Push(request) => {
+                requests.push_back(Request {
+                    id: next_id,
+                    ..request
+                });
+                next_id += 1;
+            }
+            Action::Answer(id) => {
+                if let Some(request) = requests.iter_mut().find(|request| request.id == id) {
+                    request.answered = true;
+                }
+            }
+            Action::Pop(id) => requests.retain(|request| request.id != id),
+        }
+
+        Rc::new(Self { requests, next_id })
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct UseDialogsHandle {
+    dispatch: UseReducerDispatcher<Queue>,
+}
+
+impl UseDialogsHandle {
+    async fn open(&self, kind: DialogKind) -> Answer {
+        let (sender, receiver) = oneshot::channel();
+
+        self.dispatch.dispatch(Action::Push(Request {
+            id: 0,
+            kind,
+            sender: Rc::new(RefCell::new(Some(sender))),
+            answered: false,
+        }));
+
+        // The provider was removed before an answer, treat it as cancelled
+        receiver.await.ok().flatten()
+    }
+
+    /// Asks for a confirmation, `true` when confirmed.
+    pub async fn confirm(&self, options: ConfirmOptions) -> bool {
+        self.open(DialogKind::Confirm(options)).await.is_some()
+    }
+
+    /// Shows a message, resolves once it is closed.
+    pub async fn alert(&self, options: AlertOptions) {
+        self.open(DialogKind::Alert(options)).await;
+    }
+
+    /// Asks for a text, `None` when cancelled.
+    pub async fn prompt(&self, options: PromptOptions) -> Option<String> {
+        self.open(DialogKind::Prompt(options)).await
+    }
+}
+
+/// # use_dialogs hook
+/// Confirm, alert and prompt dialogs which can be awaited.
+///  Must be used inside a [`DialogProvider`].
+///
+/// ## Example
+/// ```rust
+/// use yew::{platform::spawn_local, prelude::*};
+/// use ui_common::components::{use_dialogs, ConfirmOptions};
+///
+/// #[function_component(DeleteButton)]
+/// fn delete_button() -> Html {
+///     let dialogs = use_dialogs();
+///
+///     let onclick = Callback::from(move |_| {
+///         let dialogs = dialogs.clone();
+///
+///         spawn_local(async move {
+///             let confirmed = dialogs
+///                 .confirm(ConfirmOptions {
+///                     title: "Delete the page?".into(),
+///                     body: html! { "It can't be restored." },
+///                     danger: true,
+///                     ..ConfirmOptions::default()
+///                 })
+///                 .await;
+///
+///             if confirmed {
+///                 // Delete the page
+///             }
+///         });
+///     });
+///
+///     html! { <button {onclick}>{"Delete"}</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_dialogs() -> UseDialogsHandle {
+    use_context::<UseDialogsHandle>().expect("use_dialogs must be used inside a DialogProvider")
+}
+
+#[derive(PartialEq, Properties)]
+pub struct DialogProviderProps {
+    pub children: Children,
+}
+
+/// # DialogProvider component
+/// Shows the dialogs requested through [`use_dialogs`], one at a time,
+///  in a [`Modal`](super::Modal). Place it once near the root of the application.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::DialogProvider;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DialogProvider>
+///             <main>{"Application"}</main>
+///         </DialogProvider>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn DialogProvider(DialogProviderProps { children }: &DialogProviderProps) -> Html {
+    let queue = use_reducer(Queue::default);
+    let handle = UseDialogsHandle {
+        dispatch: queue.dispatcher(),
+    };
+
+    let dialog = queue.requests.front().map(|request| {
+        let onanswer = {
+            let request = request.clone();
+            let dispatch = queue.dispatcher();
+
+            Callback::from(move |answer: Answer| {
+                request.answer(answer);
+                dispatch.dispatch(Action::Answer(request.id));
+            })
+        };
+        let onclosed = {
+            let id = request.id;
+            let dispatch = queue.dispatcher();
+
+            Callback::from(move |_| dispatch.dispatch(Action::Pop(id)))
+        };
+
+        // Keyed so the next dialog of the queue starts from a fresh state
+        html! {
+            <Dialog
+                key={request.id}
+                kind={request.kind.clone()}
+                open={!request.answered}
+                {onanswer}
+                {onclosed}
+            />
+        }
+    });
+
+    html! {
+        <ContextProvider<UseDialogsHandle> context={handle}>
+            { for children.iter() }
+            { dialog }
+        </ContextProvider<UseDialogsHandle>>
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct DialogProps {
+    kind: DialogKind,
+    /// Whether the dialog is still waiting for an answer.
+    open: bool,
+    onanswer: Callback<Answer>,
+    /// Called once the dialog has finished closing.
+    onclosed: Callback<()>,
+}
+
+#[function_component]
+fn Dialog(
+    DialogProps {
+        kind,
+        open,
+        onanswer,
+        onclosed,
+    }: &DialogProps,
+) -> Html {
+    let value = use_state(|| match kind {
+        DialogKind::Prompt(options) => options.value.clone(),
+        _ => String::new(),
+    });
+    let input_ref = use_node_ref();
+
+    let cancel = {
+        let onanswer = onanswer.clone();
+
+        Callback::from(move |_| onanswer.emit(None))
+    };
+    let confirm = {
+        let onanswer = onanswer.clone();
+        let value = value.clone();
+
+        Callback::from(move |_| onanswer.emit(Some((*value).clone())))
+    };
+
+    let (title, body, actions, initial_focus) = match kind {
+        DialogKind::Confirm(options) => (
+            options.title.clone(),
+            options.body.clone(),
+            html! {
+                <>
+                    <Button<NoRoute> color={Color::Secondary} text={options.cancel_label.to_string()} onclick={cancel.reform(|_| ())} />
+                    <Button<NoRoute>
+                        color={if options.danger { Color::Danger } else { Color::Primary }}
+                        text={options.confirm_label.to_string()}
+                        onclick={confirm.reform(|_| ())}
+                    />
+                </>
+            },
+            None,
+        ),
+        DialogKind::Alert(options) => (
+            options.title.clone(),
+            options.body.clone(),
+            html! {
+                <Button<NoRoute> text={options.button_label.to_string()} onclick={confirm.reform(|_| ())} />
+            },
+            None,
+        ),
+        DialogKind::Prompt(options) => {
+            let oninput = {
+                let value = value.clone();
+
+                Callback::from(move |event: InputEvent| {
+                    value.set(
+                        event
+                            .target_unchecked_into::<web_sys::HtmlInputElement>()
+                            .value(),
+                    )
+                })
+            };
+            let onkeydown = {
+                let confirm = confirm.clone();
+
+                Callback::from(move |event: KeyboardEvent| {
+                    if event.key() == "Enter" {
+                        event.prevent_default();
+                        confirm.emit(());
+                    }
+                })
+            };
+
+            (
+                options.title.clone(),
+                html! {
+                    <>
+                        { options.body.clone() }
+                        <Input
+                            value={(*value).clone()}
+                            placeholder={options.placeholder.clone()}
+                            aria_label={options.title.clone()}
+                            node_ref={input_ref.clone()}
+                            {oninput}
+                            {onkeydown}
+                        />
+                    </>
+                },
+                html! {
+                    <>
+                        <Button<NoRoute> color={Color::Secondary} text={options.cancel_label.to_string()} onclick={cancel.reform(|_| ())} />
+                        <Button<NoRoute> text={options.confirm_label.to_string()} onclick={confirm.reform(|_| ())} />
+                    </>
+                },
+                Some(input_ref.clone()),
+            )
+        }
+    };
+
+    html! {
+        <Modal open={*open} on_close={cancel} on_closed={onclosed.clone()} {initial_focus}>
+            <ModalTitle>{ title }</ModalTitle>
+            <ModalBody>{ body }</ModalBody>
+            <ModalActions>{ actions }</ModalActions>
+        </Modal>
+    }
+}
@@ -0,0 +1,736 @@
+use std::rc::Rc;
+
+use gloo_utils::body;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
+use yew::prelude::*;
+
+use super::{
+    floating::{use_floating, Align, Placement, Side},
+    listbox::{roving_step, Typeahead},
+    overlay::{use_overlay, OverlayOptions},
+    tooltip::TooltipPosition,
+};
+use crate::Icon;
+
+/// What a [`MenuItem`] does.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MenuItemKind {
+    /// Runs the callback and closes the menu.
+    Action(Callback<()>),
+
+    /// Toggles a value, the callback receives the new state.
+    Checkbox {
+        checked: bool,
+        onchange: Callback<bool>,
+    },
+
+    /// Opens a nested menu.
+    Submenu(Vec<MenuItem>),
+
+    /// Line between groups of items.
+    Separator,
+
+    /// Title of the following group of items.
+    Header,
+}
+
+/// Entry of a [`DropdownMenu`] or a [`ContextMenu`](super::ContextMenu).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuItem {
+    pub kind: MenuItemKind,
+
+    /// Text to display.
+    pub label: AttrValue,
+
+    /// Icon to display before the label.
+    pub icon: Option<Icon>,
+
+    /// Whether the item can be chosen.
+    pub disabled: bool,
+}
+
+impl MenuItem {
+    fn new(kind: MenuItemKind, label: impl Into<AttrValue>) -> Self {
+        Self {
+            kind,
+            label: label.into(),
+            icon: None,
+            disabled: false,
+        }
+    }
+
+    pub fn action(label: impl Into<AttrValue>, onselect: Callback<()>) -> Self {
+        Self::new(MenuItemKind::Action(onselect), label)
+    }
+
+    pub fn checkbox(label: impl Into<AttrValue>, checked: bool, onchange: Callback<bool>) -> Self {
+        Self::new(MenuItemKind::Checkbox { checked, onchange }, label)
+    }
+
+    pub fn submenu(label: impl Into<AttrValue>, items: Vec<MenuItem>) -> Self {
+        Self::new(MenuItemKind::Submenu(items), label)
+    }
+
+    pub fn separator() -> Self {
+        Self::new(MenuItemKind::Separator, "")
+    }
+
+    pub fn header(label: impl Into<AttrValue>) -> Self {
+        Self::new(MenuItemKind::Header, label)
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Whether the item can hold the keyboard focus.
+    fn focusable(&self) -> bool {
+        !self.disabled && !matches!(self.kind, MenuItemKind::Separator | MenuItemKind::Header)
+    }
+}
+
+/// Index of the next focusable item after (or before) `from`, wrapping around.
+fn step(items: &[MenuItem], from: Option<usize>, forward: bool) -> Option<usize> {
+    roving_step(items.len(), from, forward, |index| items[index].focusable())
+}
+
+/// Whether `target` belongs to the menu `root` or one of its submenus.
+pub(crate) fn in_menu(target: Option<&Node>, root: &str) -> bool {
+    target
+        .and_then(|target| target.dyn_ref::<Element>())
+        .and_then(|target| {
+            target
+                .closest(&format!("[data-mm-menu='{root}']"))
+                .ok()
+                .flatten()
+        })
+        .is_some()
+}
+
+#[derive(PartialEq, Properties)]
+pub(crate) struct MenuListProps {
+    /// Id of the `menu` element.
+    pub id: AttrValue,
+
+    /// Id of the outermost menu, shared by its submenus.
+    pub root: AttrValue,
+
+    pub items: Vec<MenuItem>,
+
+    /// Closes the whole menu, after a choice, Escape or Tab.
+    pub onclose: Callback<()>,
+
+    /// Closes only this submenu, `None` for the outermost menu.
+    #[prop_or_default]
+    pub onback: Option<Callback<()>>,
+
+    /// Accessible name of the menu.
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// Position of the menu in viewport coordinates, as computed by `use_floating`.
+    pub top: f64,
+    pub left: f64,
+
+    /// z-index given by the overlay stack, submenus are placed above it.
+    pub z_index: usize,
+
+    pub node_ref: NodeRef,
+
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Items of a menu following the ARIA menu pattern, shared by the dropdown and context menus.
+///  The active item holds the focus, submenus open on the right.
+#[function_component]
+pub(crate) fn MenuList(props: &MenuListProps) -> Html {
+    let active = use_state(|| step(&props.items, None, true));
+    let submenu = use_state(|| None::<usize>);
+    let refs = use_memo(props.items.len(), |len| {
+        (0..*len).map(|_| NodeRef::default()).collect::<Vec<_>>()
+    });
+    let typed = use_mut_ref(Typeahead::default);
+
+    // The active item holds the focus, unless a submenu took it
+    use_effect_with((*active, *submenu), {
+        let refs = refs.clone();
+
+        move |(active, submenu)| {
+            if submenu.is_none() {
+                if let Some(item) = active
+                    .and_then(|index| refs.get(index))
+                    .and_then(|item| item.cast::<HtmlElement>())
+                {
+                    let _ = item.focus();
+                }
+            }
+        }
+    });
+
+    let activate = {
+        let items = props.items.clone();
+        let onclose = props.onclose.clone();
+        let submenu = submenu.clone();
+
+        Rc::new(move |index: usize| {
+            let Some(item) = items.get(index).filter(|item| item.focusable()) else {
+                return;
+            };
+
+            match &item.kind {
+                MenuItemKind::Action(onselect) => {
+                    onclose.emit(());
+                    onselect.emit(());
+                }
+                // Checkable items keep the menu open to toggle several of them
+                MenuItemKind::Checkbox { checked, onchange } => onchange.emit(!checked),
+                MenuItemKind::Submenu(_) => submenu.set(Some(index)),
+                MenuItemKind::Separator | MenuItemKind::Header => {}
+            }
+        })
+    };
+
+    let onkeydown = {
+        let items = props.items.clone();
+        let onclose = props.onclose.clone();
+        let onback = props.onback.clone();
+        let active = active.clone();
+        let submenu = submenu.clone();
+        let activate = activate.clone();
+
+        move |event: KeyboardEvent| {
+            let key = event.key();
+
+            match key.as_str() {
+                "ArrowDown" => active.set(step(&items, *active, true)),
+                "ArrowUp" => active.set(step(&items, *active, false)),
+                "Home" => active.set(step(&items, None, true)),
+                "End" => active.set(step(&items, None, false)),
+                "Enter" | " " => {
+                    if let Some(index) = *active {
+                        activate(index);
+                    }
+                }
+                "ArrowRight" => {
+                    if let Some(index) = active
+                        .filter(|index| matches!(items[*index].kind, MenuItemKind::Submenu(_)))
+                    {
+                        submenu.set(Some(index));
+                    }
+                }
+                "ArrowLeft" => match &onback {
+                    Some(onback) => onback.emit(()),
+                    None => return,
+                },
+                "Escape" => match &onback {
+                    Some(onback) => onback.emit(()),
+                    None => onclose.emit(()),
+                },
+                "Tab" => {
+                    // Let the focus move on, only close the menu
+                    onclose.emit(());
+                    return;
+                }
+                _ if key.chars().count() == 1 => {
+                    let found = typed.borrow_mut().find(
+                        &key,
+                        items.len(),
+                        *active,
+                        |index| items[index].focusable(),
+                        |index| &items[index].label,
+                    );
+
+                    if let Some(index) = found {
+                        active.set(Some(index));
+                    }
+                }
+                _ => return,
+            }
+
+            event.prevent_default();
+            // Submenus are rendered through portals, don't let their keys reach the parent menu
+            event.stop_propagation();
+        }
+    };
+
+    let items = props
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let label = item.label.clone();
+
+            let (role, checked) = match &item.kind {
+                MenuItemKind::Separator => {
+                    return html! {
+                        <li
+                            role="separator"
+                            class="mm-my-1 mm-h-px mm-bg-transparent-black-300 dark:mm-bg-transparent-white-300"
+                        />
+                    };
+                }
+                MenuItemKind::Header => {
+                    return html! {
+                        <li
+                            role="presentation"
+                            class="mm-pt-2 mm-pb-1 mm-px-3 mm-text-xs mm-font-medium mm-uppercase mm-tracking-wide mm-text-gray-low-100 dark:mm-text-gray-low-200"
+                        >
+                            { label }
+                        </li>
+                    };
+                }
+                MenuItemKind::Checkbox { checked, .. } => {
+                    ("menuitemcheckbox", Some(checked.to_string()))
+                }
+                MenuItemKind::Action(_) | MenuItemKind::Submenu(_) => ("menuitem", None),
+            };
+            let has_submenu = matches!(item.kind, MenuItemKind::Submenu(_));
+            let expanded = has_submenu.then(|| (*submenu == Some(index)).to_string());
+            let controls = has_submenu.then(|| format!("{}-{index}", props.id));
+
+            let class = classes!(
+                "mm-flex",
+                "mm-items-center",
+                "mm-gap-2",
+                "mm-py-2",
+                "mm-px-3",
+                "mm-rounded",
+                "mm-cursor-pointer",
+                "mm-select-none",
+                "mm-transition-colors",
+                "mm-duration-125",
+                "focus:mm-outline-none",
+                (*active == Some(index))
+                    .then_some("mm-bg-transparent-black-300 dark:mm-bg-transparent-white-300"),
+                item.disabled.then_some("mm-opacity-30 mm-cursor-not-allowed"),
+            );
+
+            let icon = match &item.kind {
+                MenuItemKind::Checkbox { checked: true, .. } => Some(Icon::CHECK),
+                MenuItemKind::Checkbox { checked: false, .. } => None,
+                _ => item.icon,
+            };
+
+            let onclick = {
+                let activate = activate.clone();
+
+                move |_| activate(index)
+            };
+
+            let onmouseenter = {
+                let active = active.clone();
+                let submenu = submenu.clone();
+                let focusable = item.focusable();
+
+                move |_| {
+                    if focusable {
+                        active.set(Some(index));
+                        submenu.set(has_submenu.then_some(index));
+                    }
+                }
+            };
+
+            html! {
+                <li
+                    {class}
+                    {role}
+                    tabindex="-1"
+                    aria-checked={checked}
+                    aria-disabled={item.disabled.to_string()}
+                    aria-haspopup={has_submenu.then_some("menu")}
+                    aria-expanded={expanded}
+                    aria-controls={controls}
+                    ref={refs[index].clone()}
+                    {onclick}
+                    {onmouseenter}
+                >
+                    <span class="mm-w-5 mm-inline-flex mm-justify-center mm-items-center mm-text-xl">
+                        if let Some(icon) = icon {
+                            { icon }
+                        }
+                    </span>
+                    <span class="mm-grow mm-truncate">{ label }</span>
+                    if has_submenu {
+                        <span class="mm-inline-flex mm-items-center">{ Icon::CARET_RIGHT }</span>
+                    }
+                </li>
+            }
+        })
+        .collect::<Html>();
+
+    let nested = submenu.and_then(|index| match &props.items.get(index)?.kind {
+        MenuItemKind::Submenu(items) => {
+            let onback = {
+                let submenu = submenu.clone();
+
+                Callback::from(move |_| submenu.set(None))
+            };
+
+            Some(html! {
+                <Submenu
+                    id={AttrValue::from(format!("{}-{index}", props.id))}
+                    root={props.root.clone()}
+                    anchor={refs[index].clone()}
+                    items={items.clone()}
+                    label={props.items[index].label.clone()}
+                    onclose={props.onclose.clone()}
+                    {onback}
+                    z_index={props.z_index + 1}
+                />
+            })
+        }
+        _ => None,
+    });
+
+    html! {
+        <ul
+            id={props.id.clone()}
+            role="menu"
+            aria-label={props.label.clone()}
+            data-mm-menu={props.root.clone()}
+            style={format!("top: {}px; left: {}px; z-index: {};", props.top, props.left, props.z_index)}
+            class={classes!(
+                "mm-fixed",
+                "mm-min-w-48",
+                "mm-max-w-xs",
+                "mm-max-h-80",
+                "mm-overflow-y-auto",
+                "mm-p-1",
+                "mm-rounded-md",
+                "mm-drop-shadow-md",
+                "mm-border",
+                "mm-border-transparent-black-400",
+                "dark:mm-border-transparent-white-400",
+                "mm-bg-gray-high-100",
+                "dark:mm-bg-gray-low-700",
+                "mm-text-sm",
+                "mm-text-gray-low-400",
+                "dark:mm-text-gray-high-700",
+                props.class.clone(),
+            )}
+            ref={props.node_ref.clone()}
+            {onkeydown}
+        >
+            { items }
+            { nested }
+        </ul>
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct SubmenuProps {
+    id: AttrValue,
+    root: AttrValue,
+    anchor: NodeRef,
+    items: Vec<MenuItem>,
+    label: AttrValue,
+    onclose: Callback<()>,
+    onback: Callback<()>,
+    z_index: usize,
+}
+
+/// Nested menu placed next to the item which opened it.
+#[function_component]
+fn Submenu(props: &SubmenuProps) -> Html {
+    let node = use_node_ref();
+    let position = use_floating(
+        props.anchor.clone(),
+        node.clone(),
+        Placement {
+            side: Some(Side::Right),
+            align: Align::Start,
+            offset: 4.0,
+            padding: 4.0,
+            arrow_padding: 0.0,
+        },
+        true,
+    );
+
+    create_portal(
+        html! {
+            <MenuList
+                id={props.id.clone()}
+                root={props.root.clone()}
+                items={props.items.clone()}
+                label={props.label.clone()}
+                onclose={props.onclose.clone()}
+                onback={props.onback.clone()}
+                top={position.top}
+                left={position.left}
+                z_index={props.z_index}
+                node_ref={node}
+            />
+        },
+        body().into(),
+    )
+}
+
+#[derive(PartialEq, Properties)]
+pub struct DropdownMenuProps {
+    /// Items of the menu
+    pub items: Vec<MenuItem>,
+
+    /// Element which opens the menu on click, e.g. a `Button`
+    pub children: Children,
+
+    /// Side of the trigger on which the menu opens, flipped
+    ///  when there is not enough room in the viewport
+    ///  default value is `TooltipPosition::Bottom`
+    #[prop_or(TooltipPosition::Bottom)]
+    pub position: TooltipPosition,
+
+    /// Alignment of the menu with the trigger
+    ///  default value is `DropdownAlign::Start`
+    #[prop_or(Align::Start)]
+    pub align: Align,
+
+    /// Offset in pixels
+    ///  default is 4px
+    #[prop_or(4)]
+    pub offset: u16,
+
+    /// Controlled visibility, the trigger, choices and clicks away
+    ///  only request changes through `on_open_change`
+    #[prop_or_default]
+    pub open: Option<bool>,
+
+    /// Called when the menu is opened or closed
+    #[prop_or_default]
+    pub on_open_change: Callback<bool>,
+
+    /// Accessible name of the menu
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// Additional classes on top of the menu
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # DropdownMenu component
+/// Menu of actions opened by clicking its children, or pressing
+///  ArrowDown on them. Items can have icons, be disabled, checkable,
+///  grouped under headers and separators, or open submenus.
+///
+/// Arrow keys, Home and End move between the items, typing jumps to
+///  the matching label, Enter or Space chooses, Escape closes.
+///  The first child element gets `aria-expanded` and `aria-controls`,
+///  so it should be the button opening the menu.
+///
+/// See [DropdownMenuProps](DropdownMenuProps) for more details.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use ui_common::{components::{Button, ButtonColor, ButtonVariant, DropdownAlign, DropdownMenu, MenuItem}, Icon};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum Page {
+///     Pages,
+/// }
+///
+/// #[function_component(DropdownMenuExample)]
+/// fn dropdown_menu_example() -> Html {
+///     let pinned = use_state(|| false);
+///
+///     let items = vec![
+///         MenuItem::header("Page"),
+///         MenuItem::action("Rename", Callback::from(|_| ())).with_icon(Icon::PENCIL_SIMPLE),
+///         MenuItem::action("Duplicate", Callback::from(|_| ())).with_icon(Icon::COPY),
+///         MenuItem::checkbox("Pinned", *pinned, Callback::from(move |value| pinned.set(value))),
+///         MenuItem::submenu("Move to", vec![
+///             MenuItem::action("Drafts", Callback::from(|_| ())),
+///             MenuItem::action("Archive", Callback::from(|_| ())).with_disabled(true),
+///         ]),
+///         MenuItem::separator(),
+///         MenuItem::action("Delete", Callback::from(|_| ())).with_icon(Icon::TRASH),
+///     ];
+///
+///     html! {
+///         <DropdownMenu {items} align={DropdownAlign::End} label="Page actions">
+///             <Button<Page>
+///                 left_icon={Some(Icon::DOTS_THREE_VERTICAL)}
+///                 color={ButtonColor::Blind}
+///                 variant={ButtonVariant::Transparent}
+///             />
+///         </DropdownMenu>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn DropdownMenu(
+    DropdownMenuProps {
+        items,
+        children,
+        position,
+        align,
+        offset,
+        open,
+        on_open_change,
+        label,
+        class,
+    }: &DropdownMenuProps,
+) -> Html {
+    let id = use_memo((), |_| format!("mm-dropdown-menu-{}", Uuid::new_v4()));
+    let visible_state = use_state(|| false);
+    let visible = open.unwrap_or(*visible_state);
+    let anchor_ref = use_node_ref();
+    let menu_ref = use_node_ref();
+
+    let coordinates = use_floating(
+        anchor_ref.clone(),
+        menu_ref.clone(),
+        Placement {
+            side: position.side(),
+            align: *align,
+            offset: *offset as f64,
+            padding: 4.0,
+            arrow_padding: 0.0,
+        },
+        visible,
+    );
+
+    let change = {
+        let internal = visible_state.clone();
+        let on_open_change = on_open_change.clone();
+
+        Callback::from(move |value: bool| {
+            if value != visible {
+                internal.set(value);
+                on_open_change.emit(value);
+            }
+        })
+    };
+
+    // Closes the menu and gives the focus back to the trigger
+    let onclose = {
+        let anchor_ref = anchor_ref.clone();
+        let change = change.clone();
+
+        Callback::from(move |_| {
+            change.emit(false);
+
+            if let Some(trigger) = anchor_ref
+                .cast::<Element>()
+                .and_then(|anchor| anchor.first_element_child())
+                .and_then(|trigger| trigger.dyn_into::<HtmlElement>().ok())
+            {
+                let _ = trigger.focus();
+            }
+        })
+    };
+
+    let on_click = {
+        let anchor_ref = anchor_ref.clone();
+        let change = change.clone();
+
+        Callback::from(move |event: MouseEvent| {
+            // Events bubble from the portal too, only the trigger toggles
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let on_trigger = anchor_ref
+                .cast::<Element>()
+                .is_some_and(|anchor| anchor.contains(target.as_ref()));
+
+            if on_trigger {
+                change.emit(!visible);
+            }
+        })
+    };
+
+    let on_key_down = {
+        let change = change.clone();
+
+        Callback::from(move |event: KeyboardEvent| {
+            if !visible && event.key() == "ArrowDown" {
+                event.prevent_default();
+                change.emit(true);
+            }
+        })
+    };
+
+    // Only the topmost overlay reacts to clicks away, Escape is handled by the menu itself
+    let z_index = use_overlay(
+        visible,
+        OverlayOptions {
+            lock_scroll: false,
+            on_escape: Some(onclose.clone()),
+            on_click_away: Some({
+                let anchor_ref = anchor_ref.clone();
+                let id = id.clone();
+                let change = change.clone();
+
+                Callback::from(move |event: Event| {
+                    let target = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<Node>().ok());
+                    let on_trigger = anchor_ref
+                        .cast::<Element>()
+                        .is_some_and(|anchor| anchor.contains(target.as_ref()));
+
+                    if !on_trigger && !in_menu(target.as_ref(), &id) {
+                        change.emit(false);
+                    }
+                })
+            }),
+        },
+    );
+
+    // Describe the state of the menu on the first child element
+    use_effect_with((id.clone(), visible), {
+        let anchor_ref = anchor_ref.clone();
+
+        move |(id, visible)| {
+            if let Some(anchor) = anchor_ref.cast::<Element>() {
+                let trigger = anchor.first_element_child().unwrap_or(anchor);
+                let _ = trigger.set_attribute("aria-haspopup", "menu");
+                let _ = trigger.set_attribute("aria-controls", id);
+                let _ = trigger.set_attribute("aria-expanded", &visible.to_string());
+            }
+        }
+    });
+
+    // Rendered into `body` like the tooltip, so it can't be clipped
+    let menu = visible.then(|| {
+        create_portal(
+            html! {
+                <MenuList
+                    id={AttrValue::from((*id).clone())}
+                    root={AttrValue::from((*id).clone())}
+                    items={items.clone()}
+                    label={label.clone()}
+                    onclose={onclose.clone()}
+                    top={coordinates.top}
+                    left={coordinates.left}
+                    {z_index}
+                    node_ref={menu_ref.clone()}
+                    class={class.clone()}
+                />
+            },
+            body().into(),
+        )
+    });
+
+    html! {
+        <span
+            onclick={on_click}
+            onkeydown={on_key_down}
+            class="mm-inline-block"
+            ref={anchor_ref}
+        >
+            { for children.iter() }
+            { menu }
+        </span>
+    }
+}
@@ -0,0 +1,410 @@
+use std::rc::Rc;
+
+use gloo_utils::{document_element, window};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{DomRect, Element, ResizeObserver};
+use yew::prelude::*;
+
+/// Rectangle in viewport coordinates, as returned by `getBoundingClientRect()`.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub(crate) struct Rect {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn right(&self) -> f64 {
+        self.left + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.top + self.height
+    }
+
+    /// Visible part of the page, without the scrollbars.
+    pub fn viewport() -> Self {
+        let root = document_element();
+
+        Self {
+            left: 0.0,
+            top: 0.0,
+            width: root.client_width() as f64,
+            height: root.client_height() as f64,
+        }
+    }
+}
+
+impl From<DomRect> for Rect {
+    fn from(rect: DomRect) -> Self {
+        Self {
+            left: rect.left(),
+            top: rect.top(),
+            width: rect.width(),
+            height: rect.height(),
+        }
+    }
+}
+
+/// Side of the anchor on which the floating element is placed.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub(crate) enum Side {
+    Top,
+    #[default]
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Side {
+    fn opposite(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Right => Side::Left,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// Alignment of the floating element along the side of the anchor.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Align {
+    /// Left edges (`Top`/`Bottom`) or top edges (`Left`/`Right`) line up
+    Start,
+    #[default]
+    Center,
+    /// Right edges (`Top`/`Bottom`) or bottom edges (`Left`/`Right`) line up
+    End,
+}
+
+/// What the caller asks for, before collisions with the viewport are resolved.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub(crate) struct Placement {
+    /// Preferred side, `None` picks the side with enough room.
+    pub side: Option<Side>,
+
+    pub align: Align,
+
+    /// Distance between the anchor and the floating element, including the arrow.
+    pub offset: f64,
+
+    /// Minimum distance between the floating element and the edges of the viewport.
+    pub padding: f64,
+
+    /// Minimum distance between the arrow and the corners of the floating element.
+    pub arrow_padding: f64,
+}
+
+/// Where the floating element ends up, in the coordinates of the anchor rectangle.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub(crate) struct Position {
+    pub top: f64,
+    pub left: f64,
+
+    /// Side which was actually used, after flipping.
+    pub side: Side,
+
+    /// Distance from the top (`Left`/`Right`) or left (`Top`/`Bottom`) edge of the floating
+    ///  element to the point facing the center of the anchor, after shifting.
+    pub arrow: f64,
+}
+
+/// Places a `floating` box of the given size next to `anchor`, inside `viewport`.
+///
+/// The preferred side is flipped to the opposite one when it doesn't fit, and the box is
+///  shifted along the side to stay inside the viewport while the arrow keeps facing the anchor.
+pub(crate) fn compute(
+    anchor: Rect,
+    floating: (f64, f64),
+    viewport: Rect,
+    placement: Placement,
+) -> Position {
+    let (width, height) = floating;
+
+    let room = |side: Side| match side {
+        Side::Top => anchor.top - viewport.top,
+        Side::Right => viewport.right() - anchor.right(),
+        Side::Bottom => viewport.bottom() - anchor.bottom(),
+        Side::Left => anchor.left - viewport.left,
+    };
+    let needed = |side: Side| {
+        let size = if side.is_vertical() { height } else { width };
+        size + placement.offset + placement.padding
+    };
+    let fits = |side: Side| room(side) >= needed(side);
+
+    let side = match placement.side {
+        Some(side) if fits(side) => side,
+        Some(side) if fits(side.opposite()) => side.opposite(),
+        Some(side) if room(side.opposite()) > room(side) => side.opposite(),
+        Some(side) => side,
+        None => [Side::Top, Side::Bottom, Side::Right, Side::Left]
+            .into_iter()
+            .find(|side| fits(*side))
+            .unwrap_or_else(|| {
+                [Side::Bottom, Side::Top, Side::Right, Side::Left]
+                    .into_iter()
+                    .max_by(|a, b| room(*a).total_cmp(&room(*b)))
+                    .unwrap_or_default()
+            }),
+    };
+
+    // Coordinate along the side: aligned, then shifted inside the viewport
+    let cross = |start: f64, length: f64, size: f64, min: f64, max: f64| {
+        let aligned = match placement.align {
+            Align::Start => start,
+            Align::Center => start + length / 2.0 - size / 2.0,
+            Align::End => start + length - size,
+        };
+        let min = min + placement.padding;
+        let max = (max - placement.padding - size).max(min);
+
+        aligned.clamp(min, max)
+    };
+    // Arrow facing the center of the anchor, kept away from the rounded corners
+    let arrow = |start: f64, length: f64, floating_start: f64, size: f64| {
+        let max = (size - placement.arrow_padding).max(placement.arrow_padding);
+
+        (start + length / 2.0 - floating_start).clamp(placement.arrow_padding, max)
+    };
+
+    match side {
+        Side::Top | Side::Bottom => {
+            let left = cross(
+                anchor.left,
+                anchor.width,
+                width,
+                viewport.left,
+                viewport.right(),
+            );
+            let top = match side {
+                Side::Top => anchor.top - height - placement.offset,
+                _ => anchor.bottom() + placement.offset,
+            };
+
+            Position {
+                top,
+                left,
+                side,
+                arrow: arrow(anchor.left, anchor.width, left, width),
+            }
+        }
+        Side::Left | Side::Right => {
+            let top = cross(
+                anchor.top,
+                anchor.height,
+                height,
+                viewport.top,
+                viewport.bottom(),
+            );
+            let left = match side {
+                Side::Left => anchor.left - width - placement.offset,
+                _ => anchor.right() + placement.offset,
+            };
+
+            Position {
+                top,
+                left,
+                side,
+                arrow: arrow(anchor.top, anchor.height, top, height),
+            }
+        }
+    }
+}
+
+/// Keeps `floating` next to `anchor` while `open` is set.
+///
+/// The position is recomputed when the page or any scrolling container scrolls,
+///  when the window is resized and when either element changes size.
+///  Everything is in viewport coordinates, so the floating element must be
+///  `position: fixed` (usually rendered through a portal into `body`).
+#[hook]
+pub(crate) fn use_floating(
+    anchor: NodeRef,
+    floating: NodeRef,
+    placement: Placement,
+    open: bool,
+) -> Position {
+    let position = use_state_eq(Position::default);
+
+    use_effect_with((open, placement), {
+        let position = position.clone();
+
+        move |(open, placement)| {
+            let placement = *placement;
+            let update = Rc::new({
+                let anchor = anchor.clone();
+                let floating = floating.clone();
+
+                move || {
+                    if let (Some(anchor), Some(floating)) =
+                        (anchor.cast::<Element>(), floating.cast::<Element>())
+                    {
+                        let size = floating.get_bounding_client_rect();
+
+                        position.set(compute(
+                            anchor.get_bounding_client_rect().into(),
+                            (size.width(), size.height()),
+                            Rect::viewport(),
+                            placement,
+                        ));
+                    }
+                }
+            });
+
+            let tracking = open.then(|| {
+                update();
+
+                let callback = Closure::<dyn Fn()>::new(move || update());
+                let function = callback.as_ref().unchecked_ref();
+                let window = window();
+
+                // Scroll events don't bubble, capture them to follow scrolling containers too
+                let _ = window.add_event_listener_with_callback_and_bool("scroll", function, true);
+                let _ = window.add_event_listener_with_callback("resize", function);
+
+                let observer = ResizeObserver::new(function).ok();
+                if let Some(observer) = &observer {
+                    for node in [&anchor, &floating] {
+                        if let Some(element) = node.cast::<Element>() {
+                            observer.observe(&element);
+                        }
+                    }
+                }
+
+                (callback, observer)
+            });
+
+            move || {
+                if let Some((callback, observer)) = tracking {
+                    let function = callback.as_ref().unchecked_ref();
+                    let window = window();
+
+                    let _ = window
+                        .remove_event_listener_with_callback_and_bool("scroll", function, true);
+                    let _ = window.remove_event_listener_with_callback("resize", function);
+
+                    if let Some(observer) = observer {
+                        observer.disconnect();
+                    }
+                }
+            }
+        }
+    });
+
+    *position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute, Align, Placement, Rect, Side};
+
+    const VIEWPORT: Rect = Rect {
+        left: 0.0,
+        top: 0.0,
+        width: 1000.0,
+        height: 800.0,
+    };
+
+    fn anchor(left: f64, top: f64, width: f64, height: f64) -> Rect {
+        Rect {
+            left,
+            top,
+            width,
+            height,
+        }
+    }
+
+    fn placement(side: Side) -> Placement {
+        Placement {
+            side: Some(side),
+            align: Align::Center,
+            offset: 8.0,
+            padding: 8.0,
+            arrow_padding: 16.0,
+        }
+    }
+
+    #[test]
+    fn keeps_preferred_side_with_enough_room() {
+        let position = compute(
+            anchor(400.0, 400.0, 100.0, 30.0),
+            (200.0, 100.0),
+            VIEWPORT,
+            placement(Side::Top),
+        );
+
+        assert_eq!(position.side, Side::Top);
+        assert_eq!(position.top, 292.0);
+        assert_eq!(position.left, 350.0);
+        assert_eq!(position.arrow, 100.0);
+    }
+
+    #[test]
+    fn flips_when_preferred_side_overflows() {
+        let position = compute(
+            anchor(400.0, 10.0, 100.0, 30.0),
+            (200.0, 100.0),
+            VIEWPORT,
+            placement(Side::Top),
+        );
+
+        assert_eq!(position.side, Side::Bottom);
+        assert_eq!(position.top, 48.0);
+
+        let position = compute(
+            anchor(900.0, 400.0, 80.0, 30.0),
+            (200.0, 100.0),
+            VIEWPORT,
+            placement(Side::Right),
+        );
+
+        assert_eq!(position.side, Side::Left);
+        assert_eq!(position.left, 692.0);
+    }
+
+    #[test]
+    fn shift_keeps_padding_from_viewport_edges() {
+        let start = compute(
+            anchor(0.0, 400.0, 40.0, 30.0),
+            (200.0, 100.0),
+            VIEWPORT,
+            placement(Side::Bottom),
+        );
+        let end = compute(
+            anchor(980.0, 400.0, 20.0, 30.0),
+            (200.0, 100.0),
+            VIEWPORT,
+            placement(Side::Bottom),
+        );
+
+        assert_eq!(start.left, 8.0);
+        assert_eq!(end.left, 792.0);
+    }
+
+    #[test]
+    fn arrow_keeps_arrow_padding_from_corners() {
+        // Facing the anchor would put the arrow 12px and 198px from the left corner
+        let start = compute(
+            anchor(0.0, 400.0, 40.0, 30.0),
+            (200.0, 100.0),
+            VIEWPORT,
+            placement(Side::Bottom),
+        );
+        let end = compute(
+            anchor(980.0, 400.0, 20.0, 30.0),
+            (200.0, 100.0),
+            VIEWPORT,
+            placement(Side::Bottom),
+        );
+
+        assert_eq!(start.arrow, 16.0);
+        assert_eq!(end.arrow, 184.0);
+    }
+}
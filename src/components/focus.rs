@@ -0,0 +1,116 @@
+use gloo_utils::document;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yew_hooks::use_event_with_window;
+
+/// Attribute marking the containers of active focus traps.
+const TRAP_ATTRIBUTE: &str = "data-mm-focus-trap";
+
+/// Elements reachable with the Tab key.
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]):not([type='hidden']), select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex='-1'])";
+
+/// Focusable descendants of `container`, in DOM order.
+pub(crate) fn focusable(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// Keeps the keyboard focus inside `container` while `active` is set.
+///
+/// On activation the focus moves to `initial_focus`, or to the first focusable
+///  element, or to the container itself (give it `tabindex="-1"`). Tab and Shift+Tab
+///  wrap around, and on deactivation the focus goes back to the previously active element.
+///
+/// When traps are nested (a popover inside a modal), the one holding the focus handles Tab.
+#[hook]
+pub(crate) fn use_focus_trap(container: NodeRef, active: bool, initial_focus: Option<NodeRef>) {
+    use_effect_with(active, {
+        let container = container.clone();
+
+        move |active| {
+            let previous = active
+                .then(|| {
+                    let previous = document().active_element();
+
+                    if let Some(container) = container.cast::<Element>() {
+                        let _ = container.set_attribute(TRAP_ATTRIBUTE, "");
+                    }
+
+                    let target = initial_focus
+                        .and_then(|node| node.cast::<HtmlElement>())
+                        .or_else(|| {
+                            container
+                                .cast::<Element>()
+                                .and_then(|container| focusable(&container).into_iter().next())
+                        })
+                        .or_else(|| container.cast::<HtmlElement>());
+                    if let Some(target) = target {
+                        let _ = target.focus();
+                    }
+
+                    previous
+                })
+                .flatten();
+
+            move || {
+                if let Some(container) = container.cast::<Element>() {
+                    let _ = container.remove_attribute(TRAP_ATTRIBUTE);
+                }
+                if let Some(previous) =
+                    previous.and_then(|node| node.dyn_into::<HtmlElement>().ok())
+                {
+                    let _ = previous.focus();
+                }
+            }
+        }
+    });
+
+    use_event_with_window("keydown", move |event: KeyboardEvent| {
+        if !active || event.key() != "Tab" || event.default_prevented() {
+            return;
+        }
+        let Some(container) = container.cast::<Element>() else {
+            return;
+        };
+
+        // Another trap holds the focus, e.g. a popover opened from this modal
+        let current = document().active_element();
+        let owner = current.as_ref().and_then(|current| {
+            current
+                .closest(&format!("[{TRAP_ATTRIBUTE}]"))
+                .ok()
+                .flatten()
+        });
+        if owner.is_some_and(|owner| owner != container) {
+            return;
+        }
+
+        let elements = focusable(&container);
+        let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+            // Nothing to move to, keep the focus on the container
+            event.prevent_default();
+            return;
+        };
+        let (edge, wrap) = match event.shift_key() {
+            true => (first, last),
+            false => (last, first),
+        };
+
+        let inside = current
+            .as_ref()
+            .is_some_and(|current| container.contains(Some(current)));
+        let on_edge = current.as_ref() == Some(AsRef::<Element>::as_ref(edge));
+
+        if !inside || on_edge {
+            event.prevent_default();
+            let _ = wrap.focus();
+        }
+    });
+}
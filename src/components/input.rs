@@ -34,30 +34,58 @@ pub struct Props {
     #[prop_or_default]
     pub required: bool,
     #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
     pub class: Classes,
 
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub inputmode: Option<AttrValue>,
+    #[prop_or_default]
+    pub autocomplete: Option<AttrValue>,
+    #[prop_or_default]
+    pub maxlength: Option<u32>,
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+
+    /// Reference to the underlying `<input>` element.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
     #[prop_or_default]
     pub onkeydown: Callback<KeyboardEvent>,
     #[prop_or_default]
     pub onkeyup: Callback<KeyboardEvent>,
+    #[prop_or_default]
+    pub oninput: Callback<InputEvent>,
+    #[prop_or_default]
+    pub onchange: Callback<Event>,
+    #[prop_or_default]
+    pub onfocus: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub onpaste: Callback<Event>,
 }
 
-#[function_component]
-pub fn Input(props: &Props) -> Html {
-    let padding_classes = match props.variant {
-        Variant::Standard => match props.size {
+/// Classes shared by every text-like control built on top of [`Input`],
+///  so they follow the same `Size` and `Variant` tokens.
+pub(crate) fn field_classes(variant: &Variant, size: &Size) -> Classes {
+    let padding_classes = match variant {
+        Variant::Standard => match size {
             Size::Small => "mm-px-2 mm-py-0.5",
             Size::Medium => "mm-px-2 mm-py-1",
             Size::Large => "mm-px-2.5 mm-py-1.5",
             Size::ExtraLarge => "mm-px-3 mm-py-2",
         },
-        Variant::Round => match props.size {
+        Variant::Round => match size {
             Size::Small => "mm-px-3.5 mm-py-0.5",
             Size::Medium => "mm-px-3.5 mm-py-1",
             Size::Large => "mm-px-4 mm-py-1.5",
             Size::ExtraLarge => "mm-px-5 mm-py-2",
         },
-        Variant::Underline => match props.size {
+        Variant::Underline => match size {
             Size::Small => "mm-py-0.5",
             Size::Medium => "mm-py-1",
             Size::Large => "mm-py-1.5",
@@ -65,20 +93,20 @@ pub fn Input(props: &Props) -> Html {
         },
     };
 
-    let text_size_classes = match props.size {
+    let text_size_classes = match size {
         Size::Small => "mm-text-md mm-font-normal",
         Size::Medium => "mm-text-md mm-font-normal",
         Size::Large => "mm-text-md mm-font-normal",
         Size::ExtraLarge => "mm-text-3xl mm-font-medium",
     };
 
-    let border_classes = match props.variant {
+    let border_classes = match variant {
         Variant::Standard | Variant::Round => "mm-border mm-border-transparent-black-700 dark:mm-border-transparent-white-600 focus:mm-border-transparent-black-800 dark:focus:mm-border-transparent-white-800",
         Variant::Underline => "mm-border-b mm-border-b-transparent-black-400 dark:mm-border-b-transparent-white-400 focus:mm-border-b-transparent-black-800 dark:focus:mm-border-b-transparent-white-800",
     };
 
-    let border_radius_classes = match props.variant {
-        Variant::Standard => match props.size {
+    let border_radius_classes = match variant {
+        Variant::Standard => match size {
             Size::Small | Size::Medium | Size::Large => "mm-rounded-md",
             Size::ExtraLarge => "mm-rounded-lg",
         },
@@ -86,7 +114,7 @@ pub fn Input(props: &Props) -> Html {
         Variant::Underline => "mm-rounded-none",
     };
 
-    let class = classes!(
+    classes!(
         "mm-w-full",
         "mm-bg-transparent",
         "autofill:mm-bg-transparent",
@@ -105,19 +133,38 @@ pub fn Input(props: &Props) -> Html {
         border_radius_classes,
         padding_classes,
         text_size_classes,
+    )
+}
+
+#[function_component]
+pub fn Input(props: &Props) -> Html {
+    let class = classes!(
+        field_classes(&props.variant, &props.size),
         props.class.clone(),
     );
 
     html! {
         <input
             { class }
+            ref={ props.node_ref.clone() }
+            id={ props.id.clone() }
             type={ props._type.clone() }
             placeholder={ props.placeholder.clone() }
             name={ props.name.clone() }
             required={ props.required }
+            disabled={ props.disabled }
             value={ props.value.clone() }
+            inputmode={ props.inputmode.clone() }
+            autocomplete={ props.autocomplete.clone() }
+            maxlength={ props.maxlength.map(|maxlength| maxlength.to_string()) }
+            aria-label={ props.aria_label.clone() }
             onkeydown={ props.onkeydown.clone() }
             onkeyup={ props.onkeyup.clone() }
+            oninput={ props.oninput.clone() }
+            onchange={ props.onchange.clone() }
+            onfocus={ props.onfocus.clone() }
+            onblur={ props.onblur.clone() }
+            onpaste={ props.onpaste.clone() }
         />
     }
 }
@@ -0,0 +1,277 @@
+use js_sys::Date;
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::Icon;
+
+/// Single option of a [`Select`](super::Select) or a [`Combobox`](super::Combobox).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectOption<V> {
+    /// Typed value emitted when the option is chosen.
+    pub value: V,
+
+    /// Text to display.
+    pub label: AttrValue,
+
+    /// Icon to display before the label.
+    pub icon: Option<Icon>,
+
+    /// Whether the option can be chosen.
+    pub disabled: bool,
+}
+
+impl<V> SelectOption<V> {
+    pub fn new(value: V, label: impl Into<AttrValue>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            icon: None,
+            disabled: false,
+        }
+    }
+}
+
+/// Id of the option element, used for `aria-activedescendant`.
+pub(crate) fn option_id(listbox_id: &str, index: usize) -> String {
+    format!("{listbox_id}-option-{index}")
+}
+
+/// Index of the next `focusable` item after (or before) `from` among `len` ones, wrapping around.
+///
+/// Shared by the keyboard navigation of listboxes, menus and tabs.
+pub(crate) fn roving_step(
+    len: usize,
+    from: Option<usize>,
+    forward: bool,
+    focusable: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let start = match (from, forward) {
+        (Some(index), true) => index + 1,
+        (Some(index), false) => index + len - 1,
+        (None, true) => 0,
+        (None, false) => len - 1,
+    };
+
+    (0..len)
+        .map(|offset| {
+            if forward {
+                (start + offset) % len
+            } else {
+                (start + len - offset) % len
+            }
+        })
+        .find(|index| focusable(*index))
+}
+
+/// Index of the first `focusable` item from `start` on, wrapping around,
+///  whose label starts with `query` (case-insensitive).
+pub(crate) fn roving_typeahead<'a>(
+    len: usize,
+    start: usize,
+    query: &str,
+    focusable: impl Fn(usize) -> bool,
+    label: impl Fn(usize) -> &'a str,
+) -> Option<usize> {
+    let query = query.to_lowercase();
+
+    (0..len)
+        .map(|offset| (start + offset) % len)
+        .find(|index| focusable(*index) && label(*index).to_lowercase().starts_with(&query))
+}
+
+/// Index of the next enabled option after (or before) `from`, wrapping around.
+pub(crate) fn step<V>(
+    options: &[SelectOption<V>],
+    from: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    roving_step(options.len(), from, forward, |index| {
+        !options[index].disabled
+    })
+}
+
+/// Time after which the typed characters are forgotten, in milliseconds.
+const TYPEAHEAD_TIMEOUT: f64 = 500.0;
+
+/// Characters typed recently and the time of the last one, to jump to the matching item.
+#[derive(Default)]
+pub(crate) struct Typeahead(String, f64);
+
+impl Typeahead {
+    /// Adds `key` to the typed characters and finds the matching item from `active` on.
+    ///
+    /// A single character moves to the next match, more of them refine the current one.
+    pub fn find<'a>(
+        &mut self,
+        key: &str,
+        len: usize,
+        active: Option<usize>,
+        focusable: impl Fn(usize) -> bool,
+        label: impl Fn(usize) -> &'a str,
+    ) -> Option<usize> {
+        let now = Date::now();
+        if now - self.1 > TYPEAHEAD_TIMEOUT {
+            self.0.clear();
+        }
+        self.0.push_str(&key.to_lowercase());
+        self.1 = now;
+
+        let start = match (self.0.chars().count(), active) {
+            (1, Some(index)) => index + 1,
+            (_, Some(index)) => index,
+            (_, None) => 0,
+        };
+
+        roving_typeahead(len, start, &self.0, focusable, label)
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub(crate) struct ListboxProps<V>
+where
+    V: PartialEq,
+{
+    /// Id of the `listbox` element.
+    pub id: AttrValue,
+
+    /// Options to render.
+    pub options: Vec<SelectOption<V>>,
+
+    /// Values which are currently selected.
+    pub selected: Vec<V>,
+
+    /// Index of the highlighted option.
+    pub active: Option<usize>,
+
+    /// Whether more than one option can be selected.
+    #[prop_or_default]
+    pub multiple: bool,
+
+    /// Whether the options are being loaded.
+    #[prop_or_default]
+    pub loading: bool,
+
+    /// Emits the index of the clicked option.
+    pub onselect: Callback<usize>,
+
+    /// Emits the index of the hovered option.
+    pub onhover: Callback<usize>,
+}
+
+/// Popup list shared by `Select` and `Combobox`, following the ARIA listbox pattern.
+#[function_component]
+pub(crate) fn Listbox<V>(props: &ListboxProps<V>) -> Html
+where
+    V: Clone + PartialEq + 'static,
+{
+    let node = use_node_ref();
+
+    // Keep the highlighted option visible when navigating with the keyboard
+    use_effect_with(props.active, {
+        let node = node.clone();
+        let id = props.id.clone();
+
+        move |active| {
+            if let (Some(active), Some(listbox)) = (active, node.cast::<Element>()) {
+                if let Ok(Some(option)) =
+                    listbox.query_selector(&format!("#{}", option_id(&id, *active)))
+                {
+                    option.scroll_into_view_with_bool(false);
+                }
+            }
+        }
+    });
+
+    let options = props
+        .options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let selected = props.selected.contains(&option.value);
+            let active = props.active == Some(index);
+
+            let class = classes!(
+                "mm-flex",
+                "mm-items-center",
+                "mm-gap-2",
+                "mm-py-2",
+                "mm-px-3",
+                "mm-rounded",
+                "mm-cursor-pointer",
+                "mm-select-none",
+                "mm-transition-colors",
+                "mm-duration-125",
+                active.then_some("mm-bg-transparent-black-300 dark:mm-bg-transparent-white-300"),
+                selected.then_some("mm-text-gray-low-800 dark:mm-text-gray-high-200 mm-font-medium"),
+                option.disabled.then_some("mm-opacity-30 mm-cursor-not-allowed"),
+            );
+
+            let onclick = {
+                let onselect = props.onselect.clone();
+                let disabled = option.disabled;
+
+                move |_| {
+                    if !disabled {
+                        onselect.emit(index);
+                    }
+                }
+            };
+
+            let onmouseenter = {
+                let onhover = props.onhover.clone();
+
+                move |_| onhover.emit(index)
+            };
+
+            html! {
+                <li
+                    {class}
+                    id={option_id(&props.id, index)}
+                    role="option"
+                    aria-selected={selected.to_string()}
+                    aria-disabled={option.disabled.to_string()}
+                    // Keep the focus on the control which opened the listbox
+                    onmousedown={|event: MouseEvent| event.prevent_default()}
+                    {onclick}
+                    {onmouseenter}
+                >
+                    if let Some(icon) = option.icon {
+                        <span class="mm-w-5 mm-inline-block mm-justify-center mm-items-center mm-flex mm-text-xl">{ icon }</span>
+                    }
+                    <span class="mm-grow mm-truncate">{ option.label.clone() }</span>
+                    if selected {
+                        <span class="mm-inline-flex mm-items-center mm-text-primary-700 dark:mm-text-primary-500">{ Icon::CHECK }</span>
+                    }
+                </li>
+            }
+        })
+        .collect::<Html>();
+
+    let status = if props.loading {
+        Some("Loading…")
+    } else if props.options.is_empty() {
+        Some("No options")
+    } else {
+        None
+    };
+
+    html! {
+        <ul
+            id={props.id.clone()}
+            ref={node}
+            role="listbox"
+            aria-multiselectable={props.multiple.to_string()}
+            aria-busy={props.loading.to_string()}
+            class="mm-absolute mm-z-10 mm-left-0 mm-top-full mm-mt-1 mm-w-full mm-max-h-60 mm-overflow-y-auto mm-p-1 mm-rounded-md mm-drop-shadow-md mm-border mm-border-transparent-black-400 dark:mm-border-transparent-white-400 mm-bg-gray-high-100 dark:mm-bg-gray-low-700 mm-text-sm mm-text-gray-low-400 dark:mm-text-gray-high-700"
+        >
+            if let Some(status) = status {
+                <li class="mm-py-2 mm-px-3 mm-text-gray-low-100 dark:mm-text-gray-low-200" role="presentation">{ status }</li>
+            }
+            { options }
+        </ul>
+    }
+}
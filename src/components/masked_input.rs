@@ -0,0 +1,286 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::input::{Input, Size, Variant};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token {
+    /// `#`
+    Digit,
+    /// `A`
+    Letter,
+    /// `*`
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Token {
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            Token::Digit => c.is_ascii_digit(),
+            Token::Letter => c.is_alphabetic(),
+            Token::Alphanumeric => c.is_alphanumeric(),
+            Token::Literal(_) => false,
+        }
+    }
+}
+
+/// Input mask such as `+1 (###) ###-####`.
+///
+/// `#` accepts a digit, `A` a letter and `*` a letter or a digit. Every other character is
+///  inserted as is, `\` escapes the next character, e.g. `\#`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mask {
+    tokens: Vec<Token>,
+}
+
+impl Mask {
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+        let mut tokens = Vec::new();
+
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '#' => Token::Digit,
+                'A' => Token::Letter,
+                '*' => Token::Alphanumeric,
+                '\\' => match chars.next() {
+                    Some(escaped) => Token::Literal(escaped),
+                    None => break,
+                },
+                c => Token::Literal(c),
+            });
+        }
+
+        Self { tokens }
+    }
+
+    /// Number of characters the user has to enter.
+    pub fn slots(&self) -> usize {
+        self.tokens
+            .iter()
+            .filter(|token| !matches!(token, Token::Literal(_)))
+            .count()
+    }
+
+    fn layout(&self, input: &str, skip_literals: bool) -> String {
+        let mut chars = input.chars().peekable();
+        let mut masked = String::new();
+        // Literals are only written once the next character is entered,
+        //  so deleting backwards never gets stuck on them
+        let mut pending = String::new();
+
+        for token in &self.tokens {
+            match token {
+                Token::Literal(literal) => {
+                    if skip_literals && chars.peek() == Some(literal) {
+                        chars.next();
+                    }
+                    pending.push(*literal);
+                }
+                token => {
+                    let Some(c) = chars.by_ref().find(|c| token.accepts(*c)) else {
+                        break;
+                    };
+                    masked.push_str(&pending);
+                    masked.push(c);
+                    pending.clear();
+                }
+            }
+        }
+
+        masked
+    }
+
+    /// Masks text typed or pasted by the user, which may already contain some of the literals.
+    ///
+    /// ```rust
+    /// use ui_common::components::Mask;
+    ///
+    /// let phone = Mask::new("+1 (###) ###-####");
+    ///
+    /// assert_eq!(phone.apply("555123"), "+1 (555) 123");
+    /// assert_eq!(phone.apply("+1 (555) 123-45678"), "+1 (555) 123-4567");
+    /// assert_eq!(phone.apply("555-abc-1"), "+1 (555) 1");
+    /// ```
+    pub fn apply(&self, input: &str) -> String {
+        self.layout(input, true)
+    }
+
+    /// Masks a raw value, e.g. `5551234567`.
+    ///
+    /// ```rust
+    /// use ui_common::components::Mask;
+    ///
+    /// assert_eq!(Mask::new("+1 (###) ###-####").format("1234567890"), "+1 (123) 456-7890");
+    /// assert_eq!(Mask::new("AA-####").format("ab1234"), "ab-1234");
+    /// ```
+    pub fn format(&self, raw: &str) -> String {
+        self.layout(raw, false)
+    }
+
+    /// Characters entered by the user, without the literals.
+    ///
+    /// ```rust
+    /// use ui_common::components::Mask;
+    ///
+    /// assert_eq!(Mask::new("+1 (###) ###-####").raw("+1 (555) 123-4567"), "5551234567");
+    /// ```
+    pub fn raw(&self, input: &str) -> String {
+        self.apply(input)
+            .chars()
+            .zip(&self.tokens)
+            .filter(|(_, token)| !matches!(token, Token::Literal(_)))
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    /// Whether every character of the mask has been entered.
+    ///
+    /// ```rust
+    /// use ui_common::components::Mask;
+    ///
+    /// let date = Mask::new("##/##/####");
+    ///
+    /// assert!(date.is_complete("24/12/2024"));
+    /// assert!(!date.is_complete("24/12"));
+    /// ```
+    pub fn is_complete(&self, input: &str) -> bool {
+        self.raw(input).chars().count() == self.slots()
+    }
+
+    /// Whether the mask only accepts digits, so a numeric keyboard can be shown.
+    pub(crate) fn is_numeric(&self) -> bool {
+        self.tokens
+            .iter()
+            .all(|token| matches!(token, Token::Digit | Token::Literal(_)))
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// Mask pattern, see [`Mask`].
+    pub mask: AttrValue,
+
+    /// Raw value, without the literals of the mask.
+    #[prop_or_default]
+    pub value: AttrValue,
+
+    /// Emits the raw value, without the literals of the mask.
+    #[prop_or_default]
+    pub onchange: Callback<String>,
+
+    /// Emits the raw value once every character of the mask has been entered.
+    #[prop_or_default]
+    pub oncomplete: Callback<String>,
+
+    #[prop_or_default]
+    pub placeholder: String,
+
+    /// Name of the form field, submitted as the raw value.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub autocomplete: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub variant: Variant,
+    #[prop_or_default]
+    pub size: Size,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub required: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # MaskedInput component
+/// [`Input`](super::Input) which formats its value with a [`Mask`] while typing.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::MaskedInput;
+///
+/// #[function_component(MaskedInputExample)]
+/// fn masked_input_example() -> Html {
+///     let phone = use_state(String::new);
+///     let onchange = {
+///         let phone = phone.clone();
+///         Callback::from(move |value: String| phone.set(value))
+///     };
+///
+///     html! {
+///         <MaskedInput
+///             mask="+1 (###) ###-####"
+///             value={(*phone).clone()}
+///             {onchange}
+///             placeholder="+1 (555) 000-0000"
+///             autocomplete="tel-national"
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn MaskedInput(props: &Props) -> Html {
+    let mask = use_memo(props.mask.clone(), |mask| Mask::new(mask));
+
+    let oninput = {
+        let mask = mask.clone();
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+        let oncomplete = props.oncomplete.clone();
+
+        Callback::from(move |event: InputEvent| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+            let text = input.value();
+            let caret = input
+                .selection_start()
+                .ok()
+                .flatten()
+                .map_or(text.len(), |caret| caret as usize);
+
+            let masked = mask.apply(&text);
+            // Masking the text before the caret tells where the caret ends up
+            let caret = mask
+                .apply(&text.chars().take(caret).collect::<String>())
+                .chars()
+                .count() as u32;
+
+            input.set_value(&masked);
+            let _ = input.set_selection_range(caret, caret);
+
+            let raw = mask.raw(&masked);
+            if raw != value.as_str() {
+                if raw.chars().count() == mask.slots() {
+                    oncomplete.emit(raw.clone());
+                }
+                onchange.emit(raw);
+            }
+        })
+    };
+
+    html! {
+        <>
+            <Input
+                value={mask.format(&props.value)}
+                placeholder={props.placeholder.clone()}
+                inputmode={mask.is_numeric().then_some(AttrValue::from("numeric"))}
+                autocomplete={props.autocomplete.clone()}
+                variant={props.variant.clone()}
+                size={props.size.clone()}
+                disabled={props.disabled}
+                required={props.required}
+                class={props.class.clone()}
+                {oninput}
+            />
+            if let Some(name) = &props.name {
+                <input type="hidden" name={name.clone()} value={props.value.clone()} />
+            }
+        </>
+    }
+}
@@ -1,32 +1,92 @@
+mod alert;
 mod avatar;
+mod avatar_group;
 mod button;
+mod calendar;
+mod checkbox;
+mod chip;
+mod choice;
+mod combobox;
+mod context_menu;
+mod date_picker;
+mod date_range_picker;
+mod dialogs;
+mod dropdown_menu;
+mod floating;
+mod focus;
 mod footer;
 mod header;
 mod heading;
 mod input;
+mod listbox;
+mod masked_input;
 mod modal;
 mod nav_link;
+mod number_input;
+mod otp_input;
+mod overlay;
 mod page_header;
+mod popover;
+mod radio;
+mod select;
+mod switch;
 mod table;
+mod tabs;
+mod tag_input;
+mod time_picker;
+mod toast;
 mod tooltip;
+mod transition;
 
+pub use alert::{Alert, AlertProps, Tone as AlertTone};
 pub use avatar::{
-    Avatar, ConnectionStatus as AvatarConnectionStatus, Size as AvatarSize,
-    Variant as AvatarVariant,
+    initials as avatar_initials, Avatar, ConnectionStatus as AvatarConnectionStatus,
+    Size as AvatarSize, Variant as AvatarVariant,
 };
+pub use avatar_group::AvatarGroup;
 pub use button::{
     Button, Color as ButtonColor, Round as ButtonRound, Size as ButtonSize,
     Variant as ButtonVariant, Width as ButtonWidth,
 };
+pub use calendar::{parse_date, parse_time, week_start_for_locale};
+pub use checkbox::Checkbox;
+pub use combobox::{Combobox, OptionsLoader as ComboboxOptionsLoader};
+pub use context_menu::{use_context_menu, ContextMenu, ContextMenuProps, UseContextMenuHandle};
+pub use date_picker::DatePicker;
+pub use date_range_picker::{DateRange, DateRangePicker, DateRangePreset};
+pub use dialogs::{
+    use_dialogs, AlertOptions, ConfirmOptions, DialogProvider, DialogProviderProps, PromptOptions,
+    UseDialogsHandle,
+};
+pub use dropdown_menu::{DropdownMenu, DropdownMenuProps, MenuItem, MenuItemKind};
+pub use floating::Align as DropdownAlign;
 pub use footer::Footer;
 pub use header::Header;
 pub use heading::{Heading, Level as HeadingLevel};
 pub use input::{Input, Size as InputSize, Variant as InputVariant};
-pub use modal::{Modal, ModalActions, ModalBody, ModalProps, ModalTitle, ModalVariant};
+pub use listbox::SelectOption;
+pub use masked_input::{Mask, MaskedInput};
+pub use modal::{Modal, ModalActions, ModalBody, ModalProps, ModalSize, ModalTitle, ModalVariant};
 pub use nav_link::NavLink;
+pub use number_input::{NumberFormat, NumberInput};
+pub use otp_input::OtpInput;
 pub use page_header::PageHeader;
+pub use popover::{Popover, PopoverProps};
+pub use radio::{Orientation as RadioOrientation, RadioGroup, RadioOption};
+pub use select::Select;
+pub use switch::Switch;
 pub use table::{
     CellRenderer as TableCellRenderer, Column as TableColumn, RowRouter as TableCellRouter, Table,
     Variant as TableVariant,
 };
-pub use tooltip::{Tooltip, TooltipPosition, TooltipProps};
+pub use tabs::{
+    RoutedTab, RoutedTabs, RoutedTabsProps, TabPanel, TabPanelProps, Tabs, TabsProps,
+    Variant as TabsVariant,
+};
+pub use tag_input::TagInput;
+pub use time_picker::TimePicker;
+pub use toast::{
+    use_toasts, Kind as ToastKind, Position as ToastPosition, ToastAction, ToastOptions,
+    ToastProvider, ToastProviderProps, UseToastsHandle,
+};
+pub use tooltip::{Tooltip, TooltipPosition, TooltipProps, TooltipTrigger};
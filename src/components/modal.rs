@@ -1,16 +1,26 @@
 use gloo_utils::body;
-use js_sys::Array;
 use serde::Serialize;
-use web_sys::HtmlElement;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, Node};
 use yew::prelude::*;
-use yew_hooks::{
-    use_click_away, use_event_with_window, use_swipe_with_options, UseSwipeDirection,
-    UseSwipeOptions,
+use yew_hooks::{use_swipe_with_options, UseSwipeDirection, UseSwipeOptions};
+
+use super::{
+    focus::use_focus_trap,
+    overlay::{use_overlay, OverlayOptions},
+    transition::use_transition,
 };
 
-// How many pixels the user has to swipe down to close the modal
+// How many pixels the user has to swipe down, or towards the edge of a drawer, to close the modal
 const MOBILE_SWIPE_THRESHOLD: i32 = 150;
 
+// Width of the `Left` and `Right` drawers when not given
+const DRAWER_WIDTH: &str = "20rem";
+
+// Length of the open and close transitions in milliseconds, matches `mm-duration-200`
+const TRANSITION_DURATION: u32 = 200;
+
 #[derive(PartialEq, Properties)]
 pub struct ModalTitleProps {
     pub children: Children,
@@ -37,6 +47,7 @@ pub fn ModalTitle(ModalTitleProps { children }: &ModalTitleProps) -> Html {
         ModalVariant::Center => classes!("mm-text-left"),
         ModalVariant::Fullscreen => classes!("mm-text-left"),
         ModalVariant::Bottom => classes!("mm-text-center"),
+        ModalVariant::Left | ModalVariant::Right => classes!("mm-text-left"),
     };
 
     let line = if context.variant == ModalVariant::Bottom {
@@ -55,14 +66,15 @@ pub fn ModalTitle(ModalTitleProps { children }: &ModalTitleProps) -> Html {
         <div class={classes!("mm-px-6", "mm-pb-3", "mm-flex", "mm-items-center", "mm-self-stretch", wrapper_classes)}>
             {line}
             <p
+                id={context.title_id.clone()}
                 class={classes!("mm-text-lg", "mm-font-bold", "mm-text-gray-low-800", "dark:mm-text-gray-high-200", "mm-grow", head_text_classes)}
             >
                 {for children.iter() }
             </p>
             {
-                if context.variant != ModalVariant::Bottom {
+                if context.variant != ModalVariant::Bottom && context.close_button {
                     html! {
-                        <button onclick={on_close} class="mm-shrink-0 mm-w-6 mm-h-6 mm-fill-gray-low-100 dark:mm-fill-gray-low-200 hover:mm-fill-gray-low-800 dark:hover:mm-fill-gray-high-200">
+                        <button onclick={on_close} aria-label="Close" class="mm-shrink-0 mm-w-6 mm-h-6 mm-fill-gray-low-100 dark:mm-fill-gray-low-200 hover:mm-fill-gray-low-800 dark:hover:mm-fill-gray-high-200">
                             <svg viewBox="0 0 256 256"><path d="M205.66,194.34a8,8,0,0,1-11.32,11.32L128,139.31,61.66,205.66a8,8,0,0,1-11.32-11.32L116.69,128,50.34,61.66A8,8,0,0,1,61.66,50.34L128,116.69l66.34-66.35a8,8,0,0,1,11.32,11.32L139.31,128Z"></path></svg>
                         </button>
                     }
@@ -91,6 +103,7 @@ pub fn ModalBody(props: &ModalBodyProps) -> Html {
             classes!("mm-max-w-2xl", "mm-mx-auto", "mm-mb-auto")
         }
         ModalVariant::Bottom => classes!(),
+        ModalVariant::Left | ModalVariant::Right => classes!(),
     };
 
     let on_touch_prevent = {
@@ -134,6 +147,7 @@ pub fn ModalActions(props: &ModalActionsProps) -> Html {
             classes!("mm-max-w-2xl", "mm-mx-auto", "mm-mt-auto")
         }
         ModalVariant::Bottom => classes!(),
+        ModalVariant::Left | ModalVariant::Right => classes!(),
     };
 
     html! {
@@ -149,6 +163,20 @@ pub enum ModalVariant {
     Center,
     Fullscreen,
     Bottom,
+    /// Drawer sliding in from the left edge
+    Left,
+    /// Drawer sliding in from the right edge
+    Right,
+}
+
+/// Maximum width of the `Center` modal
+#[derive(PartialEq, Default, Clone, Debug, Copy, Serialize)]
+pub enum ModalSize {
+    Sm,
+    #[default]
+    Md,
+    Lg,
+    Xl,
 }
 
 #[derive(PartialEq, Properties)]
@@ -159,18 +187,83 @@ pub struct ModalProps {
 
     #[prop_or_default]
     pub variant: ModalVariant,
+
+    /// Width of the `Left` and `Right` drawers, any CSS length,
+    ///  default is 20rem
+    #[prop_or(AttrValue::Static(DRAWER_WIDTH))]
+    pub width: AttrValue,
+
+    /// Maximum width of the `Center` modal
+    ///  default is `ModalSize::Md`
+    #[prop_or_default]
+    pub size: ModalSize,
+
+    /// Close the modal on a click outside of it
+    ///  default is `true`
+    #[prop_or(true)]
+    pub close_on_backdrop: bool,
+
+    /// Close the modal on Escape
+    ///  default is `true`
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+
+    /// Close the `Bottom` modal and the drawers when swiped towards their edge
+    ///  default is `true`
+    #[prop_or(true)]
+    pub close_on_swipe: bool,
+
+    /// Show the close button of the `ModalTitle`
+    ///  default is `true`
+    #[prop_or(true)]
+    pub close_button: bool,
+
+    /// Called before every request to close the modal, returning `false` keeps it open.
+    ///  To ask the user first, return `false` and emit `on_close` once confirmed.
+    #[prop_or_default]
+    pub before_close: Option<Callback<(), bool>>,
+
+    /// Called when the modal opens
+    #[prop_or_default]
+    pub on_open: Callback<()>,
+
+    /// Called once the modal is closed and its closing transition is over
+    #[prop_or_default]
+    pub on_closed: Callback<()>,
+
+    /// Element to focus when the modal opens,
+    ///  by default the first focusable element
+    #[prop_or_default]
+    pub initial_focus: Option<NodeRef>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 struct ModalContext {
     pub on_close: Callback<()>,
     pub variant: ModalVariant,
+    /// Whether the `ModalTitle` shows its close button
+    pub close_button: bool,
+    /// Id of the `ModalTitle`, which labels the dialog
+    pub title_id: AttrValue,
 }
 
 /// # Modal component
 /// Is responsible for displaying a modal dialog
 ///  on top of the current page.
 ///
+/// It fades and scales in when centered, slides up from the bottom, fades when
+///  fullscreen and slides in from its edge as a drawer. It stays mounted until
+///  the closing transition ends, then `on_closed` is called.
+///  Transitions are skipped when the user prefers reduced motion.
+///
+/// The keyboard focus stays inside the modal while it is open,
+///  and goes back to the previously focused element when it closes.
+///  Modals can be nested, Escape and clicks outside only close the topmost one.
+///
+/// The bottom modal closes when swiped down, the `Left` and `Right` drawers
+///  when swiped towards their edge. Each way of closing can be turned off,
+///  and `before_close` can keep the modal open, e.g. to keep unsaved changes.
+///
 /// See [ModalProps](ModalProps) for the properties of this component.
 ///
 /// ## Example (simplest option)
@@ -201,6 +294,88 @@ struct ModalContext {
 ///     }
 /// }
 /// ```
+///
+/// ## Example (initial focus)
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{Modal, ModalTitle, ModalBody, ModalActions};
+///
+/// #[function_component(ConfirmExample)]
+/// fn confirm_example() -> Html {
+///     let state = use_state(|| true);
+///     let cancel = use_node_ref();
+///     let on_close = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.set(false))
+///     };
+///
+///     html! {
+///         <Modal open={*state} {on_close} initial_focus={cancel.clone()}>
+///             <ModalTitle>{"Delete the page?"}</ModalTitle>
+///             <ModalActions>
+///                 <button ref={cancel}>{"Cancel"}</button>
+///                 <button>{"Delete"}</button>
+///             </ModalActions>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// ## Example (drawer)
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{Modal, ModalTitle, ModalBody, ModalVariant};
+///
+/// #[function_component(SettingsExample)]
+/// fn settings_example() -> Html {
+///     let state = use_state(|| true);
+///     let on_close = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.set(false))
+///     };
+///
+///     html! {
+///         <Modal open={*state} {on_close} variant={ModalVariant::Right} width="24rem">
+///             <ModalTitle>{"Settings"}</ModalTitle>
+///             <ModalBody>{"Settings of the page"}</ModalBody>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// ## Example (guarded close)
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{Modal, ModalTitle, ModalBody, ModalSize};
+///
+/// #[function_component(EditorExample)]
+/// fn editor_example() -> Html {
+///     let state = use_state(|| true);
+///     let dirty = use_state(|| true);
+///     let on_close = {
+///         let state = state.clone();
+///         Callback::from(move |_| state.set(false))
+///     };
+///     // Keep the modal open while there are unsaved changes
+///     let before_close = {
+///         let dirty = dirty.clone();
+///         Callback::from(move |_| !*dirty)
+///     };
+///
+///     html! {
+///         <Modal
+///             open={*state}
+///             {on_close}
+///             {before_close}
+///             close_on_backdrop={false}
+///             size={ModalSize::Lg}
+///         >
+///             <ModalTitle>{"Edit the page"}</ModalTitle>
+///             <ModalBody>{"Editor"}</ModalBody>
+///         </Modal>
+///     }
+/// }
+/// ```
 #[function_component]
 pub fn Modal(
     ModalProps {
@@ -208,21 +383,40 @@ pub fn Modal(
         variant,
         on_close,
         children,
+        width,
+        size,
+        close_on_backdrop,
+        close_on_escape,
+        close_on_swipe,
+        close_button,
+        before_close,
+        on_open,
+        on_closed,
+        initial_focus,
     }: &ModalProps,
 ) -> Html {
-    let dy_touch_coords = use_state(|| 0);
+    let swipe_distance = use_state(|| 0);
+    let transition = use_transition(*open, TRANSITION_DURATION);
+    let title_id = use_memo((), |_| format!("mm-modal-title-{}", Uuid::new_v4()));
 
     let wrapper_classes = match &variant {
         ModalVariant::Center => classes!("mm-items-center", "mm-justify-center"),
         ModalVariant::Fullscreen => classes!("mm-items-center"),
         ModalVariant::Bottom => classes!("mm-items-end"),
+        ModalVariant::Left => classes!("mm-justify-start"),
+        ModalVariant::Right => classes!("mm-justify-end"),
     };
 
     let modal_classes = match &variant {
         ModalVariant::Center => classes!(
             "mm-rounded-lg",
             "mm-w-[calc(100%-24px)]",
-            "mm-max-w-md",
+            match size {
+                ModalSize::Sm => "mm-max-w-sm",
+                ModalSize::Md => "mm-max-w-md",
+                ModalSize::Lg => "mm-max-w-lg",
+                ModalSize::Xl => "mm-max-w-xl",
+            },
             "mm-max-h-[calc(100vh-16px)]",
             "mm-mt-4",
             "mm-items-center"
@@ -234,101 +428,215 @@ pub fn Modal(
             "mm-max-h-[65vh]",
             "mm-rounded-t-2xl",
             "mm-transition-transform",
-            "mm-duration-75"
+            // Follow the finger closely while it drags the modal
+            if *swipe_distance > 0 {
+                "mm-duration-75"
+            } else {
+                "mm-duration-200"
+            }
+        ),
+        ModalVariant::Left | ModalVariant::Right => classes!(
+            "mm-h-full",
+            // Leave room to click away on small screens
+            "mm-max-w-[calc(100%-24px)]",
+            "mm-transition-transform",
+            if *swipe_distance > 0 {
+                "mm-duration-75"
+            } else {
+                "mm-duration-200"
+            }
         ),
     };
 
+    let transition_classes = match &variant {
+        ModalVariant::Center if transition.shown => {
+            classes!(
+                "mm-transition",
+                "mm-duration-200",
+                "mm-opacity-100",
+                "mm-scale-100"
+            )
+        }
+        ModalVariant::Center => {
+            classes!(
+                "mm-transition",
+                "mm-duration-200",
+                "mm-opacity-0",
+                "mm-scale-95"
+            )
+        }
+        ModalVariant::Fullscreen if transition.shown => {
+            classes!("mm-transition-opacity", "mm-duration-200", "mm-opacity-100")
+        }
+        ModalVariant::Fullscreen => {
+            classes!("mm-transition-opacity", "mm-duration-200", "mm-opacity-0")
+        }
+        ModalVariant::Bottom | ModalVariant::Left | ModalVariant::Right => classes!(),
+    };
+
+    // The swipe and the slide of the bottom modal and the drawers both move them towards their edge
+    let modal_style = match (&variant, transition.shown) {
+        (ModalVariant::Bottom, true) => format!("transform: translateY({}px)", *swipe_distance),
+        (ModalVariant::Bottom, false) => "transform: translateY(100%)".to_string(),
+        (ModalVariant::Left, true) => {
+            format!(
+                "width: {width}; transform: translateX(-{}px)",
+                *swipe_distance
+            )
+        }
+        (ModalVariant::Left, false) => format!("width: {width}; transform: translateX(-100%)"),
+        (ModalVariant::Right, true) => {
+            format!(
+                "width: {width}; transform: translateX({}px)",
+                *swipe_distance
+            )
+        }
+        (ModalVariant::Right, false) => format!("width: {width}; transform: translateX(100%)"),
+        _ => String::new(),
+    };
+
     let node = use_node_ref();
+
+    // Every way of closing goes through `before_close`
+    let on_close = {
+        let before_close = before_close.clone();
+        let on_close = on_close.clone();
+
+        Callback::from(move |_| {
+            if before_close
+                .as_ref()
+                .is_none_or(|before_close| before_close.emit(()))
+            {
+                on_close.emit(());
+            }
+        })
+    };
+
     let context = ModalContext {
         on_close: on_close.clone(),
         variant: *variant,
+        close_button: *close_button,
+        title_id: AttrValue::from((*title_id).clone()),
     };
 
+    use_focus_trap(node.clone(), *open, initial_focus.clone());
+
     let swipe = use_swipe_with_options(
         NodeRef::default(),
         UseSwipeOptions {
             onswipeend: Some(Box::new({
-                let dy_touch_coords = dy_touch_coords.clone();
+                let swipe_distance = swipe_distance.clone();
                 let on_close = on_close.clone();
+                let open = *open;
 
                 move |_e, _direction| {
-                    if *dy_touch_coords > MOBILE_SWIPE_THRESHOLD {
+                    // The swipe is tracked on the whole window, closed modals ignore it
+                    if open && *swipe_distance > MOBILE_SWIPE_THRESHOLD {
                         on_close.emit(());
                     }
 
-                    dy_touch_coords.set(0);
+                    swipe_distance.set(0);
                 }
             })),
             ..Default::default()
         },
     );
 
-    use_effect_with(swipe.length_y.clone(), {
-        let dy_touch_coords = dy_touch_coords.clone();
+    let swipeable = *close_on_swipe && *open;
+    use_effect_with((*swipe.length_x, *swipe.length_y, swipeable), {
+        let swipe_distance = swipe_distance.clone();
+        let variant = *variant;
 
-        move |_| {
-            if *swipe.direction == UseSwipeDirection::Down {
-                dy_touch_coords.set(-*swipe.length_y);
+        move |(length_x, length_y, swipeable)| {
+            if !*swipeable {
+                return;
             }
-        }
-    });
 
-    use_event_with_window("keydown", {
-        let on_close = on_close.clone();
-
-        move |event: web_sys::KeyboardEvent| {
-            // Close the modal when the Escape key is pressed
-            if event.key() == "Escape" {
-                on_close.emit(());
+            // Only a swipe towards the edge the modal came from moves it
+            match (variant, &*swipe.direction) {
+                (ModalVariant::Bottom, UseSwipeDirection::Down) => swipe_distance.set(-*length_y),
+                (ModalVariant::Left, UseSwipeDirection::Left) => swipe_distance.set(*length_x),
+                (ModalVariant::Right, UseSwipeDirection::Right) => swipe_distance.set(-*length_x),
+                _ => {}
             }
         }
     });
 
-    use_click_away(node.clone(), {
-        let on_close = on_close.clone();
+    // Escape and clicks outside of the modal close it, unless another
+    //  overlay was opened on top of it
+    let z_index = use_overlay(
+        *open,
+        OverlayOptions {
+            lock_scroll: true,
+            on_escape: close_on_escape.then(|| on_close.clone()),
+            on_click_away: close_on_backdrop.then(|| {
+                let node = node.clone();
+                let on_close = on_close.clone();
 
-        move |_event: Event| {
-            on_close.emit(());
-        }
-    });
+                Callback::from(move |event: Event| {
+                    let target = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<Node>().ok());
+                    let inside = node
+                        .cast::<Node>()
+                        .is_some_and(|node| node.contains(target.as_ref()));
+
+                    if !inside {
+                        on_close.emit(());
+                    }
+                })
+            }),
+        },
+    );
 
-    // Add the `overflow-hidden` class to the body
-    //  when the modal is open to prevent scrolling
     use_effect_with(*open, {
-        let open = *open;
+        let on_open = on_open.clone();
 
-        move |_| {
-            if open {
-                let classes = body().class_list();
-                let classes_to_add = Array::new_with_length(1);
-                classes_to_add.set(0, "mm-overflow-hidden".into());
+        move |open| {
+            if *open {
+                on_open.emit(());
+            }
+        }
+    });
 
-                classes.add(&classes_to_add).expect("Class must be removed");
-            } else {
-                let classes = body().class_list();
-                let classes_to_remove = Array::new_with_length(1);
-                classes_to_remove.set(0, "mm-overflow-hidden".into());
+    // Whether the modal was mounted, to tell its closing from the first render
+    let was_mounted = use_mut_ref(|| false);
+    use_effect_with(transition.mounted, {
+        let on_closed = on_closed.clone();
 
-                classes
-                    .remove(&classes_to_remove)
-                    .expect("Class must be removed");
+        move |mounted| {
+            if !*mounted && *was_mounted.borrow() {
+                on_closed.emit(());
             }
+            *was_mounted.borrow_mut() = *mounted;
         }
     });
 
-    if !open {
+    if !transition.mounted {
         return html! {};
     }
 
+    let backdrop_classes = if transition.shown {
+        classes!("mm-opacity-100")
+    } else {
+        // Clicks go through the modal while it closes
+        classes!("mm-opacity-0", "mm-pointer-events-none")
+    };
+
     return create_portal(
         html! {
             <ContextProvider<ModalContext> context={context}>
                 <div
-                    class={classes!("mm-fixed", "mm-left-0", "mm-top-0", "mm-w-screen", "mm-h-screen", "mm-bg-black", "mm-bg-opacity-80", "mm-flex", wrapper_classes)}
+                    class={classes!("mm-fixed", "mm-left-0", "mm-top-0", "mm-w-screen", "mm-h-screen", "mm-bg-black", "mm-bg-opacity-80", "mm-flex", "mm-transition-opacity", "mm-duration-200", "motion-reduce:mm-transition-none", backdrop_classes, wrapper_classes)}
+                    style={format!("z-index: {z_index}")}
                 >
                     <div
-                        class={classes!("mm-bg-gray-high-200", "dark:mm-bg-gray-low-800", "mm-flex", "mm-flex-col", modal_classes)}
-                        style={format!("transform: translateY({}px)", *dy_touch_coords)}
+                        class={classes!("mm-bg-gray-high-200", "dark:mm-bg-gray-low-800", "mm-flex", "mm-flex-col", "focus:mm-outline-none", "mm-ease-out", "motion-reduce:mm-transition-none", modal_classes, transition_classes)}
+                        style={modal_style}
+                        role="dialog"
+                        aria-modal="true"
+                        aria-labelledby={(*title_id).clone()}
+                        tabindex="-1"
                         ref={node}
                     >
                         { for children.iter() }
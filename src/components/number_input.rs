@@ -0,0 +1,433 @@
+use js_sys::{Array, Intl, Object, Reflect};
+use uuid::Uuid;
+use wasm_bindgen::JsValue;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::{
+    calendar::browser_locale,
+    input::{field_classes, Size, Variant},
+};
+use crate::Icon;
+
+/// Separators used to format and parse numbers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumberFormat {
+    /// Separator of the fractional part.
+    pub decimal: char,
+
+    /// Separator of the groups of thousands.
+    pub group: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal: '.',
+            group: Some(','),
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Separators of the locale, as reported by `Intl.NumberFormat` of the browser.
+    ///
+    /// The parts of the formatted number name the separators, so locales with native
+    ///  digits, e.g. `ar-EG` or `hi-IN-u-nu-deva`, are read correctly too.
+    pub fn from_locale(locale: &str) -> Self {
+        let formatter = Intl::NumberFormat::new(&Array::of1(&locale.into()), &Object::new());
+        let part = |part: &JsValue, key: &str| {
+            Reflect::get(part, &key.into())
+                .ok()
+                .and_then(|value| value.as_string())
+        };
+
+        let mut format = Self {
+            decimal: Self::default().decimal,
+            group: None,
+        };
+        for value in formatter.format_to_parts(1_234_567.5).iter() {
+            let separator = part(&value, "value").and_then(|value| value.chars().next());
+            match (part(&value, "type").as_deref(), separator) {
+                (Some("decimal"), Some(decimal)) => format.decimal = decimal,
+                (Some("group"), Some(group)) => format.group = Some(group),
+                _ => {}
+            }
+        }
+
+        format
+    }
+
+    /// Formats the value, rounded to `precision` fractional digits when set.
+    ///
+    /// ```rust
+    /// use ui_common::components::NumberFormat;
+    ///
+    /// let german = NumberFormat { decimal: ',', group: Some('.') };
+    ///
+    /// assert_eq!(german.format(1234567.891, Some(2)), "1.234.567,89");
+    /// assert_eq!(NumberFormat::default().format(-1234.5, None), "-1,234.5");
+    /// assert_eq!(NumberFormat::default().format(0.75, Some(0)), "1");
+    /// ```
+    pub fn format(&self, value: f64, precision: Option<u8>) -> String {
+        let formatted = match precision {
+            Some(precision) => format!("{:.*}", precision as usize, value),
+            None => value.to_string(),
+        };
+
+        let (sign, formatted) = match formatted.strip_prefix('-') {
+            Some(formatted) => ("-", formatted),
+            None => ("", formatted.as_str()),
+        };
+        let (integer, fraction) = formatted.split_once('.').unwrap_or((formatted, ""));
+
+        let mut result = sign.to_string();
+        for (index, digit) in integer.chars().enumerate() {
+            if let Some(group) = self.group {
+                if index > 0 && (integer.len() - index) % 3 == 0 {
+                    result.push(group);
+                }
+            }
+            result.push(digit);
+        }
+
+        if !fraction.is_empty() {
+            result.push(self.decimal);
+            result.push_str(fraction);
+        }
+
+        result
+    }
+
+    /// Parses a number typed by the user, ignoring group separators and whitespace.
+    ///
+    /// ```rust
+    /// use ui_common::components::NumberFormat;
+    ///
+    /// let german = NumberFormat { decimal: ',', group: Some('.') };
+    ///
+    /// assert_eq!(german.parse("1.234,5"), Some(1234.5));
+    /// assert_eq!(NumberFormat::default().parse(" −1,234.50 "), Some(-1234.5));
+    /// assert_eq!(NumberFormat::default().parse("12abc"), None);
+    /// ```
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let normalized = text
+            .trim()
+            .chars()
+            .filter(|c| Some(*c) != self.group && !c.is_whitespace())
+            .map(|c| match c {
+                '−' => '-',
+                c if c == self.decimal => '.',
+                c => c,
+            })
+            .collect::<String>();
+
+        if normalized.is_empty() {
+            return None;
+        }
+
+        normalized
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+}
+
+/// Clamps the value to the bounds and rounds it to the precision.
+fn normalize(value: f64, min: Option<f64>, max: Option<f64>, precision: Option<u8>) -> f64 {
+    let value = match precision {
+        Some(precision) => {
+            let factor = 10_f64.powi(precision as i32);
+            (value * factor).round() / factor
+        }
+        None => value,
+    };
+
+    let value = min.map_or(value, |min| value.max(min));
+    max.map_or(value, |max| value.min(max))
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// Current value.
+    #[prop_or_default]
+    pub value: Option<f64>,
+
+    /// Emits the new value, or `None` when the field is cleared.
+    #[prop_or_default]
+    pub onchange: Callback<Option<f64>>,
+
+    #[prop_or_default]
+    pub min: Option<f64>,
+    #[prop_or_default]
+    pub max: Option<f64>,
+
+    /// Amount added or subtracted by the step buttons and the arrow keys.
+    #[prop_or(1.0)]
+    pub step: f64,
+
+    /// Number of fractional digits, e.g. `2` for most currencies.
+    #[prop_or_default]
+    pub precision: Option<u8>,
+
+    /// Separators, taken from the browser locale when not set.
+    #[prop_or_default]
+    pub format: Option<NumberFormat>,
+
+    /// Text before the value, e.g. a currency symbol.
+    #[prop_or_default]
+    pub prefix: Option<AttrValue>,
+
+    /// Text after the value, e.g. a unit.
+    #[prop_or_default]
+    pub suffix: Option<AttrValue>,
+
+    /// Show the step buttons.
+    #[prop_or(true)]
+    pub buttons: bool,
+
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+
+    /// Name of the form field, submitted without group separators and with a `.` decimal separator.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    /// Variant of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Size of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub size: Size,
+
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub required: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # NumberInput component
+/// Numeric field with locale formatting, step buttons, clamping and precision.
+///
+/// ## Example (currency)
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::NumberInput;
+///
+/// #[function_component(NumberInputExample)]
+/// fn number_input_example() -> Html {
+///     let price = use_state(|| Some(9.99));
+///     let onchange = {
+///         let price = price.clone();
+///         Callback::from(move |value| price.set(value))
+///     };
+///
+///     html! {
+///         <NumberInput
+///             value={*price}
+///             {onchange}
+///             min={0.0}
+///             step={0.5}
+///             precision={2}
+///             prefix="€"
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn NumberInput(props: &Props) -> Html {
+    let id = use_memo((), |_| format!("mm-number-input-{}", Uuid::new_v4()));
+    let format = use_memo(props.format, |format| {
+        format.unwrap_or_else(|| NumberFormat::from_locale(&browser_locale()))
+    });
+    let text = use_state(String::new);
+
+    use_effect_with((props.value, *format, props.precision), {
+        let text = text.clone();
+
+        move |(value, format, precision)| {
+            text.set(
+                value
+                    .map(|value| format.format(value, *precision))
+                    .unwrap_or_default(),
+            );
+        }
+    });
+
+    let (min, max, precision) = (props.min, props.max, props.precision);
+
+    let emit = {
+        let value = props.value;
+        let onchange = props.onchange.clone();
+        let text = text.clone();
+        let format = format.clone();
+
+        move |new: Option<f64>| {
+            let new = new.map(|new| normalize(new, min, max, precision));
+
+            if new != value {
+                onchange.emit(new);
+            } else {
+                // The text may differ from the value, e.g. `1.50` for `1.5`
+                text.set(
+                    value
+                        .map(|value| format.format(value, precision))
+                        .unwrap_or_default(),
+                );
+            }
+        }
+    };
+
+    let increment = {
+        let value = props.value;
+        let step = props.step;
+        let emit = emit.clone();
+
+        move |direction: f64| {
+            let base = value.or(min).unwrap_or_default();
+            emit(Some(base + step * direction));
+        }
+    };
+
+    let commit = {
+        let text = text.clone();
+        let format = format.clone();
+        let value = props.value;
+        let emit = emit.clone();
+
+        move || {
+            if text.trim().is_empty() {
+                emit(None);
+                return;
+            }
+
+            match format.parse(&text) {
+                Some(parsed) => emit(Some(parsed)),
+                None => emit(value),
+            }
+        }
+    };
+
+    let oninput = {
+        let text = text.clone();
+
+        move |event: InputEvent| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+
+            text.set(input.value());
+        }
+    };
+
+    let onblur = {
+        let commit = commit.clone();
+
+        move |_| commit()
+    };
+
+    let onkeydown = {
+        let increment = increment.clone();
+
+        move |event: KeyboardEvent| match event.key().as_str() {
+            "ArrowUp" => {
+                event.prevent_default();
+                increment(1.0);
+            }
+            "ArrowDown" => {
+                event.prevent_default();
+                increment(-1.0);
+            }
+            "Enter" => commit(),
+            _ => {}
+        }
+    };
+
+    let decrease = {
+        let increment = increment.clone();
+
+        move |_| increment(-1.0)
+    };
+    let increase = move |_| increment(1.0);
+
+    let at_min = matches!((props.value, min), (Some(value), Some(min)) if value <= min);
+    let at_max = matches!((props.value, max), (Some(value), Some(max)) if value >= max);
+
+    let step_button_classes = "mm-inline-flex mm-items-center mm-justify-center mm-shrink-0 mm-w-6 mm-h-6 mm-rounded mm-text-base mm-text-gray-low-100 dark:mm-text-gray-low-200 hover:mm-bg-transparent-black-300 dark:hover:mm-bg-transparent-white-200 disabled:mm-opacity-30 disabled:hover:mm-bg-transparent";
+
+    let class = classes!(
+        field_classes(&props.variant, &props.size),
+        "mm-flex",
+        "mm-items-center",
+        "mm-gap-2",
+        "focus-within:mm-border-transparent-black-800",
+        "dark:focus-within:mm-border-transparent-white-800",
+        props
+            .disabled
+            .then_some("mm-bg-gray-high-300 dark:mm-bg-gray-low-900 mm-cursor-not-allowed"),
+        props.class.clone(),
+    );
+
+    html! {
+        <div {class}>
+            if let Some(prefix) = &props.prefix {
+                <span class="mm-shrink-0 mm-text-gray-low-100 dark:mm-text-gray-low-200">{ prefix.clone() }</span>
+            }
+            <input
+                id={(*id).clone()}
+                class="mm-flex-1 mm-min-w-0 mm-bg-transparent mm-outline-none mm-tabular-nums placeholder:mm-text-gray-high-900 placeholder:dark:mm-text-gray-low-400 disabled:mm-cursor-not-allowed"
+                type="text"
+                inputmode={if precision == Some(0) { "numeric" } else { "decimal" }}
+                autocomplete="off"
+                role="spinbutton"
+                aria-valuenow={props.value.map(|value| value.to_string())}
+                aria-valuemin={min.map(|min| min.to_string())}
+                aria-valuemax={max.map(|max| max.to_string())}
+                placeholder={props.placeholder.clone()}
+                value={(*text).clone()}
+                disabled={props.disabled}
+                required={props.required}
+                {oninput}
+                {onblur}
+                {onkeydown}
+            />
+            if let Some(suffix) = &props.suffix {
+                <span class="mm-shrink-0 mm-text-gray-low-100 dark:mm-text-gray-low-200">{ suffix.clone() }</span>
+            }
+            if props.buttons {
+                <span class="mm-inline-flex mm-items-center mm-gap-0.5">
+                    <button
+                        type="button"
+                        tabindex="-1"
+                        class={step_button_classes}
+                        aria-label="Decrease"
+                        disabled={props.disabled || at_min}
+                        onclick={decrease}
+                    >
+                        { Icon::MINUS }
+                    </button>
+                    <button
+                        type="button"
+                        tabindex="-1"
+                        class={step_button_classes}
+                        aria-label="Increase"
+                        disabled={props.disabled || at_max}
+                        onclick={increase}
+                    >
+                        { Icon::PLUS }
+                    </button>
+                </span>
+            }
+            if let Some(name) = &props.name {
+                <input
+                    type="hidden"
+                    name={name.clone()}
+                    value={props.value.map(|value| value.to_string()).unwrap_or_default()}
+                />
+            }
+        </div>
+    }
+}
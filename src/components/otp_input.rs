@@ -0,0 +1,251 @@
+use wasm_bindgen::JsCast;
+use web_sys::{ClipboardEvent, HtmlInputElement};
+use yew::prelude::*;
+
+use super::input::{Input, Size, Variant};
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// Number of characters of the code.
+    #[prop_or(6)]
+    pub length: usize,
+
+    /// Characters entered so far.
+    #[prop_or_default]
+    pub value: AttrValue,
+
+    #[prop_or_default]
+    pub onchange: Callback<String>,
+
+    /// Emits the code once every character has been entered.
+    #[prop_or_default]
+    pub oncomplete: Callback<String>,
+
+    /// Only accept digits.
+    #[prop_or(true)]
+    pub numeric: bool,
+
+    /// Accessible label of the group of boxes.
+    #[prop_or(AttrValue::from("One-time code"))]
+    pub label: AttrValue,
+
+    /// Name of the form field, submitted as the whole code.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub variant: Variant,
+    #[prop_or_default]
+    pub size: Size,
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Writes `text` over the code from `index`, returns the new code and the number of written characters.
+fn fill(value: &str, index: usize, text: &str, length: usize, numeric: bool) -> (String, usize) {
+    let mut chars = value.chars().collect::<Vec<_>>();
+    let index = index.min(chars.len());
+    let accepted = text
+        .chars()
+        .filter(|c| match numeric {
+            true => c.is_ascii_digit(),
+            false => c.is_alphanumeric(),
+        })
+        .take(length - index)
+        .collect::<Vec<_>>();
+
+    let end = (index + accepted.len()).min(chars.len());
+    chars.splice(index..end, accepted.iter().copied());
+    chars.truncate(length);
+
+    (chars.into_iter().collect(), accepted.len())
+}
+
+/// # OtpInput component
+/// One [`Input`](super::Input) box per character of a one-time code. Focus moves to the next box
+///  while typing and back on Backspace, and a pasted or autofilled code is spread over the boxes.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::OtpInput;
+///
+/// #[function_component(OtpInputExample)]
+/// fn otp_input_example() -> Html {
+///     let code = use_state(String::new);
+///     let onchange = {
+///         let code = code.clone();
+///         Callback::from(move |value: String| code.set(value))
+///     };
+///     let verified = use_state(|| false);
+///     let oncomplete = {
+///         let verified = verified.clone();
+///         Callback::from(move |code: String| verified.set(code == "123456"))
+///     };
+///
+///     html! {
+///         <OtpInput value={(*code).clone()} {onchange} {oncomplete} />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn OtpInput(props: &Props) -> Html {
+    let length = props.length.max(1);
+    let refs = use_memo(length, |length| {
+        (0..*length).map(|_| NodeRef::default()).collect::<Vec<_>>()
+    });
+
+    let chars = props.value.chars().collect::<Vec<_>>();
+
+    let focus = {
+        let refs = refs.clone();
+
+        move |index: usize| {
+            if let Some(input) = refs
+                .get(index.min(length - 1))
+                .and_then(|node| node.cast::<HtmlInputElement>())
+            {
+                let _ = input.focus();
+                input.select();
+            }
+        }
+    };
+
+    let update = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+        let oncomplete = props.oncomplete.clone();
+        let numeric = props.numeric;
+        let focus = focus.clone();
+
+        move |index: usize, text: &str| {
+            let (code, written) = fill(&value, index, text, length, numeric);
+
+            if code != value.as_str() {
+                if code.chars().count() == length {
+                    oncomplete.emit(code.clone());
+                }
+                onchange.emit(code.clone());
+            }
+
+            focus(index.min(code.chars().count()) + written);
+        }
+    };
+
+    let boxes = (0..length).map(|index| {
+        let current = chars.get(index).copied();
+
+        let oninput = {
+            let update = update.clone();
+
+            Callback::from(move |event: InputEvent| {
+                let input = event.target_unchecked_into::<HtmlInputElement>();
+                let mut text = input.value();
+
+                // The caret was before or after the existing character, keep only what was typed
+                if let Some(current) = current {
+                    if text.chars().count() > 1 {
+                        if let Some(position) = text.find(current) {
+                            text.remove(position);
+                        }
+                    }
+                }
+
+                input.set_value(&current.map(String::from).unwrap_or_default());
+                if !text.is_empty() {
+                    update(index, &text);
+                }
+            })
+        };
+
+        let onpaste = {
+            let update = update.clone();
+
+            Callback::from(move |event: Event| {
+                let text = event
+                    .dyn_ref::<ClipboardEvent>()
+                    .and_then(|event| event.clipboard_data())
+                    .and_then(|data| data.get_data("text").ok());
+
+                if let Some(text) = text {
+                    event.prevent_default();
+                    update(index, &text);
+                }
+            })
+        };
+
+        let onkeydown = {
+            let value = props.value.clone();
+            let onchange = props.onchange.clone();
+            let focus = focus.clone();
+
+            Callback::from(move |event: KeyboardEvent| match event.key().as_str() {
+                "Backspace" => {
+                    event.prevent_default();
+                    // An empty box removes the character of the previous one
+                    let target = match current {
+                        Some(_) => Some(index),
+                        None => index.checked_sub(1),
+                    };
+
+                    if let Some(target) = target {
+                        let mut code = value.chars().collect::<Vec<_>>();
+                        if target < code.len() {
+                            code.remove(target);
+                            onchange.emit(code.into_iter().collect());
+                        }
+                        focus(target);
+                    }
+                }
+                "ArrowLeft" if index > 0 => {
+                    event.prevent_default();
+                    focus(index - 1);
+                }
+                "ArrowRight" => {
+                    event.prevent_default();
+                    focus(index + 1);
+                }
+                _ => {}
+            })
+        };
+
+        let onfocus = Callback::from(|event: FocusEvent| {
+            event.target_unchecked_into::<HtmlInputElement>().select();
+        });
+
+        html! {
+            <Input
+                node_ref={refs[index].clone()}
+                value={current.map(String::from).unwrap_or_default()}
+                inputmode={AttrValue::from(if props.numeric { "numeric" } else { "text" })}
+                autocomplete={(index == 0).then_some(AttrValue::from("one-time-code"))}
+                aria_label={AttrValue::from(format!("Character {} of {length}", index + 1))}
+                variant={props.variant.clone()}
+                size={props.size.clone()}
+                disabled={props.disabled}
+                class="!mm-w-12 mm-text-center mm-tabular-nums"
+                {oninput}
+                {onpaste}
+                {onkeydown}
+                {onfocus}
+            />
+        }
+    });
+
+    html! {
+        <div
+            class={classes!("mm-flex", "mm-gap-2", props.class.clone())}
+            role="group"
+            aria-label={props.label.clone()}
+        >
+            { for boxes }
+            if let Some(name) = &props.name {
+                <input type="hidden" name={name.clone()} value={props.value.clone()} />
+            }
+        </div>
+    }
+}
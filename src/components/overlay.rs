@@ -0,0 +1,203 @@
+use std::cell::{Cell, RefCell};
+
+use gloo_utils::{body, window};
+use js_sys::Array;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Event, KeyboardEvent};
+use yew::prelude::*;
+
+/// z-index of the lowest overlay, above the regular content of the page.
+const BASE_Z_INDEX: usize = 1000;
+
+/// Distance between the z-indexes of two stacked overlays,
+///  leaving room for the elements an overlay places above itself.
+const Z_INDEX_STEP: usize = 10;
+
+/// z-index of the toasts, above any realistic stack of overlays, including
+///  the ones opened while a toast is already on screen.
+pub(crate) const TOAST_Z_INDEX: usize = 5000;
+
+struct Entry {
+    id: usize,
+    on_escape: Option<Callback<()>>,
+    on_click_away: Option<Callback<Event>>,
+}
+
+thread_local! {
+    /// Open overlays, the topmost one last.
+    static STACK: RefCell<Vec<Entry>> = const { RefCell::new(Vec::new()) };
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+    /// Number of open overlays which prevent the page from scrolling.
+    static SCROLL_LOCKS: Cell<usize> = const { Cell::new(0) };
+    /// Whether the window listeners dispatching to the topmost overlay are installed.
+    static LISTENING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn z_index_at(position: usize) -> usize {
+    BASE_Z_INDEX + position * Z_INDEX_STEP
+}
+
+/// z-index just above the topmost overlay, for elements such as tooltips
+///  which must stay visible without taking part in the stack.
+pub(crate) fn top_z_index() -> usize {
+    STACK.with(|stack| z_index_at(stack.borrow().len()))
+}
+
+/// Calls `pick` on the topmost overlay, outside of the borrow of the stack.
+fn dispatch_to_top<T>(pick: impl Fn(&Entry) -> Option<Callback<T>>, value: T) {
+    let callback = STACK.with(|stack| stack.borrow().last().and_then(pick));
+
+    if let Some(callback) = callback {
+        callback.emit(value);
+    }
+}
+
+fn install_listeners() {
+    LISTENING.with(|listening| {
+        if listening.replace(true) {
+            return;
+        }
+
+        let window = window();
+
+        let keydown = Closure::<dyn Fn(Event)>::new(|event: Event| {
+            let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+                return;
+            };
+            // Menus and tooltips handle Escape themselves and prevent the default,
+            //  to close a submenu or a tooltip before the overlay around it
+            if event.key() == "Escape" && !event.default_prevented() {
+                dispatch_to_top(|entry| entry.on_escape.clone(), ());
+            }
+        });
+        let pointerdown = Closure::<dyn Fn(Event)>::new(|event: Event| {
+            dispatch_to_top(|entry| entry.on_click_away.clone(), event);
+        });
+
+        let _ =
+            window.add_event_listener_with_callback("keydown", keydown.as_ref().unchecked_ref());
+        for name in ["mousedown", "touchstart"] {
+            let _ =
+                window.add_event_listener_with_callback(name, pointerdown.as_ref().unchecked_ref());
+        }
+
+        // Shared by every overlay for the lifetime of the page
+        keydown.forget();
+        pointerdown.forget();
+    });
+}
+
+fn set_scroll_lock(locked: bool) {
+    let classes = body().class_list();
+    let class = Array::of1(&"mm-overflow-hidden".into());
+
+    if locked {
+        classes.add(&class).expect("Class must be added");
+    } else {
+        classes.remove(&class).expect("Class must be removed");
+    }
+}
+
+/// Prevents the page from scrolling until every lock is released.
+fn lock_scroll() {
+    SCROLL_LOCKS.with(|locks| {
+        if locks.get() == 0 {
+            set_scroll_lock(true);
+        }
+        locks.set(locks.get() + 1);
+    });
+}
+
+fn unlock_scroll() {
+    SCROLL_LOCKS.with(|locks| {
+        let count = locks.get().saturating_sub(1);
+        locks.set(count);
+
+        if count == 0 {
+            set_scroll_lock(false);
+        }
+    });
+}
+
+#[derive(Default)]
+pub(crate) struct OverlayOptions {
+    /// Prevent the page from scrolling while the overlay is open.
+    pub lock_scroll: bool,
+
+    /// Called on Escape, only when the overlay is the topmost one.
+    pub on_escape: Option<Callback<()>>,
+
+    /// Called on every mouse or touch press, only when the overlay is the topmost one.
+    ///  The callback decides whether the press happened outside of the overlay.
+    pub on_click_away: Option<Callback<Event>>,
+}
+
+/// Registers an overlay (modal, drawer, popover, menu…) in the global stack while `open` is set,
+///  and returns its z-index.
+///
+/// Overlays opened later are stacked above the earlier ones. Only the topmost overlay receives
+///  Escape and click-away, so one key press closes one overlay. Scroll locks are counted, the page
+///  scrolls again once the last overlay which locked it is closed.
+#[hook]
+pub(crate) fn use_overlay(open: bool, options: OverlayOptions) -> usize {
+    let id = *use_memo((), |_| {
+        NEXT_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        })
+    });
+
+    let OverlayOptions {
+        lock_scroll: lock,
+        on_escape,
+        on_click_away,
+    } = options;
+
+    // Keep the latest callbacks, they capture the state of the last render
+    STACK.with(|stack| {
+        if let Some(entry) = stack.borrow_mut().iter_mut().find(|entry| entry.id == id) {
+            entry.on_escape = on_escape.clone();
+            entry.on_click_away = on_click_away.clone();
+        }
+    });
+
+    use_effect_with((open, lock), move |(open, lock)| {
+        let registered = *open;
+        let locked = *open && *lock;
+
+        if registered {
+            install_listeners();
+            STACK.with(|stack| {
+                stack.borrow_mut().push(Entry {
+                    id,
+                    on_escape,
+                    on_click_away,
+                })
+            });
+        }
+        if locked {
+            lock_scroll();
+        }
+
+        move || {
+            if registered {
+                STACK.with(|stack| stack.borrow_mut().retain(|entry| entry.id != id));
+            }
+            if locked {
+                unlock_scroll();
+            }
+        }
+    });
+
+    // Before the first registration the overlay is about to be pushed on top
+    STACK.with(|stack| {
+        let stack = stack.borrow();
+        let position = stack
+            .iter()
+            .position(|entry| entry.id == id)
+            .unwrap_or(stack.len());
+
+        z_index_at(position)
+    })
+}
@@ -0,0 +1,258 @@
+use gloo_utils::body;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
+use yew::prelude::*;
+
+use super::{
+    floating::use_floating,
+    focus::use_focus_trap,
+    overlay::{use_overlay, OverlayOptions},
+    tooltip::{arrow_classes, floating_style, TooltipPosition},
+};
+
+#[derive(PartialEq, Properties)]
+pub struct PopoverProps {
+    /// Content of the popover, e.g. a form, a list or buttons
+    pub content: Html,
+
+    /// Element which opens the popover on click
+    pub children: Children,
+
+    /// Position of the popover, flipped to the opposite side
+    ///  when there is not enough room in the viewport
+    ///  default value is `TooltipPosition::Bottom`
+    #[prop_or(TooltipPosition::Bottom)]
+    pub position: TooltipPosition,
+
+    /// Show arrow
+    ///  default value is `true`
+    #[prop_or(true)]
+    pub arrow: bool,
+
+    /// Offset in pixels
+    ///  default is 8px
+    #[prop_or(8)]
+    pub offset: u16,
+
+    /// Controlled visibility, clicks, Escape and clicks away
+    ///  only request changes through `on_open_change`
+    #[prop_or_default]
+    pub open: Option<bool>,
+
+    /// Called when the popover is opened or closed
+    #[prop_or_default]
+    pub on_open_change: Callback<bool>,
+
+    /// Keep the keyboard focus inside the popover while it is open
+    #[prop_or_default]
+    pub trap_focus: bool,
+
+    /// Accessible name of the popover
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// Additional classes on top of the popover
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Popover component
+/// Popover component shows interactive content next to its children
+///  when they are clicked, and closes on a click away or Escape.
+///
+/// It is placed like a [`Tooltip`](super::Tooltip) and shares its arrow.
+///  The first child element gets `aria-expanded` and `aria-controls`,
+///  so it should be the button opening the popover.
+///
+/// See [PopoverProps](PopoverProps) for more details.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use ui_common::components::{Button, Input, Popover};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum Page {
+///     Rename,
+/// }
+///
+/// #[function_component(PopoverExample)]
+/// fn popover_example() -> Html {
+///     let content = html! {
+///         <form class="mm-flex mm-flex-col mm-gap-2">
+///             <Input placeholder="Name" />
+///             <Button<Page> text="Save" />
+///         </form>
+///     };
+///
+///     html! {
+///         <Popover {content} label="Rename" trap_focus={true}>
+///             <Button<Page> text="Rename" />
+///         </Popover>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Popover(
+    PopoverProps {
+        content,
+        children,
+        position,
+        arrow,
+        offset,
+        open,
+        on_open_change,
+        trap_focus,
+        label,
+        class,
+    }: &PopoverProps,
+) -> Html {
+    let id = use_memo((), |_| format!("mm-popover-{}", Uuid::new_v4()));
+    let visible_state = use_state(|| false);
+    let visible = open.unwrap_or(*visible_state);
+    let anchor_ref = use_node_ref();
+    let popover_ref = use_node_ref();
+
+    let coordinates = use_floating(
+        anchor_ref.clone(),
+        popover_ref.clone(),
+        position.placement(*offset, *arrow),
+        visible,
+    );
+    use_focus_trap(popover_ref.clone(), visible && *trap_focus, None);
+
+    let change = {
+        let internal = visible_state.clone();
+        let on_open_change = on_open_change.clone();
+
+        Callback::from(move |value: bool| {
+            if value != visible {
+                internal.set(value);
+                on_open_change.emit(value);
+            }
+        })
+    };
+
+    let on_click = {
+        let anchor_ref = anchor_ref.clone();
+        let change = change.clone();
+
+        Callback::from(move |event: MouseEvent| {
+            // Events bubble from the portal too, only the trigger toggles
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let on_trigger = anchor_ref
+                .cast::<Element>()
+                .is_some_and(|anchor| anchor.contains(target.as_ref()));
+
+            if on_trigger {
+                change.emit(!visible);
+            }
+        })
+    };
+
+    // Only the topmost overlay reacts to Escape and clicks away
+    let z_index = use_overlay(
+        visible,
+        OverlayOptions {
+            lock_scroll: false,
+            on_escape: Some({
+                let anchor_ref = anchor_ref.clone();
+                let change = change.clone();
+
+                Callback::from(move |_| {
+                    change.emit(false);
+
+                    // Give the focus back to the trigger
+                    if let Some(trigger) = anchor_ref
+                        .cast::<Element>()
+                        .and_then(|anchor| anchor.first_element_child())
+                        .and_then(|trigger| trigger.dyn_into::<HtmlElement>().ok())
+                    {
+                        let _ = trigger.focus();
+                    }
+                })
+            }),
+            on_click_away: Some({
+                let anchor_ref = anchor_ref.clone();
+                let popover_ref = popover_ref.clone();
+                let change = change.clone();
+
+                Callback::from(move |event: Event| {
+                    // Clicks on the trigger are handled by its own toggle
+                    let target = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<Node>().ok());
+                    let inside = [&anchor_ref, &popover_ref].into_iter().any(|node| {
+                        node.cast::<Node>()
+                            .is_some_and(|node| node.contains(target.as_ref()))
+                    });
+
+                    if !inside {
+                        change.emit(false);
+                    }
+                })
+            }),
+        },
+    );
+
+    // Describe the state of the popover on the first child element
+    use_effect_with((id.clone(), visible), {
+        let anchor_ref = anchor_ref.clone();
+
+        move |(id, visible)| {
+            if let Some(anchor) = anchor_ref.cast::<Element>() {
+                let trigger = anchor.first_element_child().unwrap_or(anchor);
+                let _ = trigger.set_attribute("aria-haspopup", "dialog");
+                let _ = trigger.set_attribute("aria-controls", id);
+                let _ = trigger.set_attribute("aria-expanded", &visible.to_string());
+            }
+        }
+    });
+
+    let popover_classes = classes!(
+        "mm-fixed",
+        "mm-p-4",
+        "mm-rounded",
+        "mm-shadow-lg",
+        "mm-max-w-sm",
+        "mm-text-sm",
+        "mm-text-gray-low-800",
+        "dark:mm-text-gray-high-200",
+        "mm-bg-gray-high-200",
+        "dark:mm-bg-gray-low-800",
+        "focus:mm-outline-none",
+        arrow.then(|| arrow_classes(coordinates.side)),
+        class.clone(),
+    );
+
+    // Rendered into `body` like the tooltip, so it can't be clipped
+    let popover = visible.then(|| {
+        create_portal(
+            html! {
+                <div
+                    id={(*id).clone()}
+                    role="dialog"
+                    aria-label={label.clone()}
+                    tabindex="-1"
+                    class={popover_classes}
+                    style={floating_style(&coordinates, z_index)}
+                    ref={popover_ref}
+                >
+                    { content.clone() }
+                </div>
+            },
+            body().into(),
+        )
+    });
+
+    html! {
+        <span onclick={on_click} class="mm-inline-block" ref={anchor_ref}>
+            { for children.iter() }
+            { popover }
+        </span>
+    }
+}
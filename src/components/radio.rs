@@ -0,0 +1,206 @@
+use uuid::Uuid;
+use yew::prelude::*;
+
+use super::choice::{ChoiceLabel, CONTROL_CLASSES};
+
+/// Single option of a [`RadioGroup`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadioOption<V> {
+    /// Typed value emitted when the option is chosen.
+    pub value: V,
+
+    /// Text next to the radio button.
+    pub label: AttrValue,
+
+    /// Secondary text under the label.
+    pub description: Option<AttrValue>,
+
+    /// Value submitted with the form, defaults to the index of the option.
+    pub form_value: Option<AttrValue>,
+
+    /// Whether the option can be chosen.
+    pub disabled: bool,
+}
+
+impl<V> RadioOption<V> {
+    pub fn new(value: V, label: impl Into<AttrValue>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            description: None,
+            form_value: None,
+            disabled: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum Orientation {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props<V>
+where
+    V: PartialEq,
+{
+    /// Options to choose from.
+    pub options: Vec<RadioOption<V>>,
+
+    /// Currently selected value.
+    #[prop_or_default]
+    pub value: Option<V>,
+
+    /// Emits the value of the chosen option.
+    #[prop_or_default]
+    pub onchange: Callback<V>,
+
+    /// Caption of the whole group.
+    #[prop_or_default]
+    pub label: AttrValue,
+
+    /// Name of the form field, generated when not set.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub orientation: Orientation,
+
+    /// Disables every option of the group.
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub required: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # RadioGroup component
+/// Group of styled native radio buttons with typed values.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{RadioGroup, RadioOption};
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Plan {
+///     Free,
+///     Pro,
+/// }
+///
+/// #[function_component(RadioGroupExample)]
+/// fn radio_group_example() -> Html {
+///     let plan = use_state(|| Plan::Free);
+///     let onchange = {
+///         let plan = plan.clone();
+///         Callback::from(move |value| plan.set(value))
+///     };
+///
+///     html! {
+///         <RadioGroup<Plan>
+///             label="Plan"
+///             options={vec![
+///                 RadioOption::new(Plan::Free, "Free"),
+///                 RadioOption::new(Plan::Pro, "Pro"),
+///             ]}
+///             value={Some((*plan).clone())}
+///             {onchange}
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn RadioGroup<V>(props: &Props<V>) -> Html
+where
+    V: Clone + PartialEq + 'static,
+{
+    let id = use_memo((), |_| format!("mm-radio-{}", Uuid::new_v4()));
+    let name = props
+        .name
+        .clone()
+        .unwrap_or_else(|| AttrValue::from((*id).clone()));
+
+    let options = props
+        .options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let option_id = format!("{id}-{index}");
+            let description_id = format!("{option_id}-description");
+            let disabled = props.disabled || option.disabled;
+
+            let onchange = {
+                let onchange = props.onchange.clone();
+                let value = option.value.clone();
+
+                move |_: Event| onchange.emit(value.clone())
+            };
+
+            html! {
+                <label
+                    for={option_id.clone()}
+                    class="mm-inline-flex mm-items-start mm-gap-2 mm-select-none"
+                >
+                    <span class="mm-relative mm-inline-flex mm-mt-0.5">
+                        <input
+                            id={option_id}
+                            type="radio"
+                            class={classes!(
+                                CONTROL_CLASSES,
+                                "mm-w-4",
+                                "mm-h-4",
+                                "mm-rounded-full",
+                                "mm-border",
+                                "mm-border-gray-high-950",
+                                "dark:mm-border-gray-low-300",
+                                "checked:mm-border-primary-700",
+                                "dark:checked:mm-border-primary-500",
+                            )}
+                            name={name.clone()}
+                            value={option.form_value.clone().unwrap_or_else(|| index.to_string().into())}
+                            checked={props.value.as_ref() == Some(&option.value)}
+                            {disabled}
+                            required={props.required}
+                            aria-describedby={option.description.is_some().then(|| description_id.clone())}
+                            {onchange}
+                        />
+                        <span class="mm-pointer-events-none mm-absolute mm-inset-1 mm-rounded-full mm-bg-primary-700 dark:mm-bg-primary-500 mm-scale-0 peer-checked:mm-scale-100 mm-transition-transform mm-duration-125"></span>
+                    </span>
+                    <ChoiceLabel
+                        label={option.label.clone()}
+                        description={option.description.clone()}
+                        {description_id}
+                    />
+                </label>
+            }
+        })
+        .collect::<Html>();
+
+    let options_classes = match props.orientation {
+        Orientation::Vertical => "mm-flex mm-flex-col mm-gap-3",
+        Orientation::Horizontal => "mm-flex mm-flex-row mm-flex-wrap mm-gap-x-6 mm-gap-y-3",
+    };
+
+    html! {
+        <fieldset
+            role="radiogroup"
+            aria-labelledby={(!props.label.is_empty()).then(|| format!("{id}-label"))}
+            disabled={props.disabled}
+            class={classes!("mm-flex", "mm-flex-col", "mm-gap-2", props.class.clone())}
+        >
+            if !props.label.is_empty() {
+                <legend id={format!("{id}-label")} class="mm-mb-2 mm-text-sm mm-font-medium mm-text-gray-low-800 dark:mm-text-gray-high-200">
+                    { props.label.clone() }
+                </legend>
+            }
+            <div class={options_classes}>
+                { options }
+            </div>
+        </fieldset>
+    }
+}
@@ -0,0 +1,272 @@
+use uuid::Uuid;
+use yew::prelude::*;
+use yew_hooks::use_click_away;
+
+use super::{
+    input::{field_classes, Size, Variant},
+    listbox::{option_id, step, Listbox, SelectOption, Typeahead},
+};
+use crate::Icon;
+
+#[derive(PartialEq, Properties)]
+pub struct Props<V>
+where
+    V: PartialEq,
+{
+    /// Options to choose from.
+    pub options: Vec<SelectOption<V>>,
+
+    /// Currently selected value.
+    #[prop_or_default]
+    pub value: Option<V>,
+
+    /// Emits the value of the chosen option.
+    #[prop_or_default]
+    pub onchange: Callback<V>,
+
+    /// Text to display when nothing is selected.
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+
+    /// Variant of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Size of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub size: Size,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Select component
+/// Styled replacement of the native `<select>` for typed options.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{Select, SelectOption};
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Region {
+///     Europe,
+///     America,
+/// }
+///
+/// #[function_component(SelectExample)]
+/// fn select_example() -> Html {
+///     let region = use_state(|| None);
+///     let onchange = {
+///         let region = region.clone();
+///         Callback::from(move |value| region.set(Some(value)))
+///     };
+///
+///     html! {
+///         <Select<Region>
+///             options={vec![
+///                 SelectOption::new(Region::Europe, "Europe"),
+///                 SelectOption::new(Region::America, "America"),
+///             ]}
+///             value={(*region).clone()}
+///             {onchange}
+///             placeholder="Region"
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Select<V>(props: &Props<V>) -> Html
+where
+    V: Clone + PartialEq + 'static,
+{
+    let id = use_memo((), |_| format!("mm-select-{}", Uuid::new_v4()));
+    let listbox_id = format!("{id}-listbox");
+
+    let open = use_state(|| false);
+    let active = use_state(|| None::<usize>);
+    let typed = use_mut_ref(Typeahead::default);
+    let node = use_node_ref();
+
+    use_click_away(node.clone(), {
+        let open = open.clone();
+
+        move |_event: Event| {
+            open.set(false);
+        }
+    });
+
+    let selected_index = props.value.as_ref().and_then(|value| {
+        props
+            .options
+            .iter()
+            .position(|option| &option.value == value)
+    });
+
+    let choose = {
+        let open = open.clone();
+        let onchange = props.onchange.clone();
+        let options = props.options.clone();
+
+        Callback::from(move |index: usize| {
+            if let Some(option) = options.get(index).filter(|option| !option.disabled) {
+                onchange.emit(option.value.clone());
+            }
+            open.set(false);
+        })
+    };
+
+    let show = {
+        let open = open.clone();
+        let active = active.clone();
+        let options = props.options.clone();
+
+        Callback::from(move |_: ()| {
+            active.set(selected_index.or_else(|| step(&options, None, true)));
+            open.set(true);
+        })
+    };
+
+    let onclick = {
+        let open = open.clone();
+        let show = show.clone();
+        let disabled = props.disabled;
+
+        move |_| {
+            if disabled {
+                return;
+            }
+
+            if *open {
+                open.set(false);
+            } else {
+                show.emit(());
+            }
+        }
+    };
+
+    let onkeydown = {
+        let open = open.clone();
+        let active = active.clone();
+        let typed = typed.clone();
+        let choose = choose.clone();
+        let options = props.options.clone();
+        let disabled = props.disabled;
+
+        move |event: KeyboardEvent| {
+            if disabled {
+                return;
+            }
+
+            let key = event.key();
+            match key.as_str() {
+                "ArrowDown" | "ArrowUp" => {
+                    event.prevent_default();
+                    if *open {
+                        active.set(step(&options, *active, key == "ArrowDown"));
+                    } else {
+                        show.emit(());
+                    }
+                }
+                "Home" | "End" if *open => {
+                    event.prevent_default();
+                    active.set(step(&options, None, key == "Home"));
+                }
+                "Enter" | " " => {
+                    event.prevent_default();
+                    match (*open, *active) {
+                        (true, Some(index)) => choose.emit(index),
+                        (true, None) => open.set(false),
+                        (false, _) => show.emit(()),
+                    }
+                }
+                "Escape" if *open => {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    open.set(false);
+                }
+                "Tab" => open.set(false),
+                _ if key.chars().count() == 1 => {
+                    let found = typed.borrow_mut().find(
+                        &key,
+                        options.len(),
+                        (*active).or(selected_index),
+                        |index| !options[index].disabled,
+                        |index| &options[index].label,
+                    );
+
+                    if let Some(index) = found {
+                        active.set(Some(index));
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+
+    let onhover = {
+        let active = active.clone();
+
+        Callback::from(move |index| active.set(Some(index)))
+    };
+
+    let label = match selected_index.and_then(|index| props.options.get(index)) {
+        Some(option) => html! {
+            <span class="mm-flex mm-items-center mm-gap-2 mm-truncate">
+                if let Some(icon) = option.icon {
+                    <span class="mm-inline-flex mm-items-center mm-text-xl">{ icon }</span>
+                }
+                { option.label.clone() }
+            </span>
+        },
+        None => html! {
+            <span class="mm-truncate mm-text-gray-high-900 dark:mm-text-gray-low-400">{ props.placeholder.clone() }</span>
+        },
+    };
+
+    let class = classes!(
+        field_classes(&props.variant, &props.size),
+        "mm-flex",
+        "mm-items-center",
+        "mm-justify-between",
+        "mm-gap-2",
+        "mm-text-left",
+        "disabled:mm-cursor-not-allowed",
+        props.class.clone(),
+    );
+
+    html! {
+        <div class="mm-relative mm-w-full" ref={node}>
+            <button
+                {class}
+                id={(*id).clone()}
+                type="button"
+                role="combobox"
+                aria-haspopup="listbox"
+                aria-expanded={open.to_string()}
+                aria-controls={listbox_id.clone()}
+                aria-activedescendant={(*open).then(|| (*active).map(|index| option_id(&listbox_id, index))).flatten()}
+                disabled={props.disabled}
+                {onclick}
+                {onkeydown}
+            >
+                { label }
+                <span class="mm-inline-flex mm-items-center mm-shrink-0 mm-text-gray-low-100 dark:mm-text-gray-low-200">{ Icon::CARET_DOWN }</span>
+            </button>
+            if *open {
+                <Listbox<V>
+                    id={listbox_id}
+                    options={props.options.clone()}
+                    selected={props.value.clone().into_iter().collect::<Vec<_>>()}
+                    active={*active}
+                    onselect={choose}
+                    {onhover}
+                />
+            }
+        </div>
+    }
+}
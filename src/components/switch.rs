@@ -0,0 +1,116 @@
+use uuid::Uuid;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::choice::{ChoiceLabel, CONTROL_CLASSES};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    /// Whether the switch is on.
+    #[prop_or_default]
+    pub checked: bool,
+
+    /// Emits the new state.
+    #[prop_or_default]
+    pub onchange: Callback<bool>,
+
+    /// Text next to the switch.
+    #[prop_or_default]
+    pub label: AttrValue,
+
+    /// Secondary text under the label.
+    #[prop_or_default]
+    pub description: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Name of the form field.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    /// Value submitted with the form when the switch is on.
+    #[prop_or(AttrValue::from("on"))]
+    pub value: AttrValue,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Switch component
+/// Toggle for settings which apply immediately.
+///
+/// It is a native checkbox with `role="switch"`, so it is submitted with forms as one.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::Switch;
+///
+/// #[function_component(SwitchExample)]
+/// fn switch_example() -> Html {
+///     let enabled = use_state(|| true);
+///     let onchange = {
+///         let enabled = enabled.clone();
+///         Callback::from(move |value| enabled.set(value))
+///     };
+///
+///     html! {
+///         <Switch checked={*enabled} {onchange} label="Dark mode" />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Switch(props: &Props) -> Html {
+    let id = use_memo((), |_| format!("mm-switch-{}", Uuid::new_v4()));
+    let description_id = format!("{id}-description");
+
+    let onchange = {
+        let onchange = props.onchange.clone();
+
+        move |event: Event| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+
+            onchange.emit(input.checked());
+        }
+    };
+
+    html! {
+        <label
+            for={(*id).clone()}
+            class={classes!("mm-inline-flex", "mm-items-start", "mm-gap-3", "mm-select-none", props.class.clone())}
+        >
+            <span class="mm-relative mm-inline-flex">
+                <input
+                    id={(*id).clone()}
+                    type="checkbox"
+                    role="switch"
+                    class={classes!(
+                        CONTROL_CLASSES,
+                        "mm-w-9",
+                        "mm-h-5",
+                        "mm-rounded-full",
+                        "mm-bg-gray-high-700",
+                        "dark:mm-bg-gray-low-300",
+                        "checked:mm-bg-primary-700",
+                        "dark:checked:mm-bg-primary-500",
+                    )}
+                    checked={props.checked}
+                    disabled={props.disabled}
+                    name={props.name.clone()}
+                    value={props.value.clone()}
+                    aria-checked={props.checked.to_string()}
+                    aria-describedby={props.description.is_some().then(|| description_id.clone())}
+                    {onchange}
+                />
+                <span class="mm-pointer-events-none mm-absolute mm-top-0.5 mm-left-0.5 mm-w-4 mm-h-4 mm-rounded-full mm-bg-white mm-shadow mm-transition-transform mm-duration-125 mm-ease-in-out peer-checked:mm-translate-x-4"></span>
+            </span>
+            <ChoiceLabel
+                label={props.label.clone()}
+                description={props.description.clone()}
+                description_id={description_id}
+            />
+        </label>
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::HashSet;
+
+use gloo_utils::document;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yew_nested_router::{components::*, target::Target};
+
+use super::{focus::focusable, listbox::roving_step};
+use crate::Icon;
+
+#[derive(PartialEq, Default, Clone, Copy, Debug)]
+pub enum Variant {
+    /// Tabs on a line, the selected one underlined
+    #[default]
+    Underline,
+    /// Rounded tabs, the selected one filled
+    Pill,
+}
+
+/// Classes of the list, of every tab, and of the selected and other tabs.
+fn variant_classes(variant: Variant) -> (Classes, Classes, Classes, Classes) {
+    let tab = classes!(
+        "mm-inline-flex",
+        "mm-shrink-0",
+        "mm-items-center",
+        "mm-gap-2",
+        "mm-text-sm",
+        "mm-font-medium",
+        "mm-whitespace-nowrap",
+        "mm-transition-colors",
+        "mm-duration-125",
+        "mm-outline-none",
+        "focus-visible:mm-ring-2",
+        "focus-visible:mm-ring-primary-500",
+        "disabled:mm-opacity-30",
+        "disabled:mm-cursor-not-allowed"
+    );
+    let inactive = classes!(
+        "mm-text-gray-low-100",
+        "hover:mm-text-gray-low-400",
+        "dark:mm-text-gray-low-200",
+        "dark:hover:mm-text-gray-high-700"
+    );
+
+    match variant {
+        Variant::Underline => (
+            classes!(
+                "mm-gap-4",
+                "mm-border-b",
+                "mm-border-b-transparent-black-400",
+                "dark:mm-border-b-transparent-white-400"
+            ),
+            classes!(tab, "mm-px-1", "mm-py-2", "mm-border-b-2", "-mm-mb-px"),
+            classes!(
+                "mm-border-primary-700",
+                "dark:mm-border-primary-500",
+                "mm-text-gray-low-800",
+                "dark:mm-text-gray-high-200"
+            ),
+            classes!("mm-border-transparent", inactive),
+        ),
+        Variant::Pill => (
+            classes!("mm-gap-1"),
+            classes!(tab, "mm-px-3", "mm-py-1.5", "mm-rounded-full"),
+            classes!(
+                "mm-bg-primary-700",
+                "dark:mm-bg-primary-500",
+                "mm-text-white"
+            ),
+            classes!(
+                inactive,
+                "hover:mm-bg-transparent-black-300",
+                "dark:hover:mm-bg-transparent-white-300"
+            ),
+        ),
+    }
+}
+
+/// Icon, title and badge of a tab.
+fn tab_content(icon: Option<Icon>, title: &AttrValue, badge: Option<&AttrValue>) -> Html {
+    html! {
+        <>
+            if let Some(icon) = icon {
+                <span class="mm-inline-flex mm-items-center mm-text-lg">{ icon }</span>
+            }
+            { title.clone() }
+            if let Some(badge) = badge {
+                <span class="mm-inline-flex mm-items-center mm-rounded-full mm-px-1.5 mm-min-w-[20px] mm-justify-center mm-text-xs mm-bg-transparent-black-300 dark:mm-bg-transparent-white-300">
+                    { badge.clone() }
+                </span>
+            }
+        </>
+    }
+}
+
+/// Index of the tab reached by `key` from `current`, among the `enabled` ones of `len`.
+fn step(key: &str, len: usize, current: usize, enabled: impl Fn(usize) -> bool) -> Option<usize> {
+    match key {
+        "ArrowRight" => roving_step(len, Some(current), true, enabled),
+        "ArrowLeft" => roving_step(len, Some(current), false, enabled),
+        "Home" => roving_step(len, None, true, enabled),
+        "End" => roving_step(len, None, false, enabled),
+        _ => None,
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct TabPanelProps {
+    /// Text of the tab
+    pub title: AttrValue,
+
+    /// Content of the panel
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Icon before the title
+    #[prop_or_default]
+    pub icon: Option<Icon>,
+
+    /// Small counter or label after the title, e.g. a number of comments
+    #[prop_or_default]
+    pub badge: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// # TabPanel component
+/// Content of one tab of [`Tabs`], its props describe the tab.
+#[function_component]
+pub fn TabPanel(TabPanelProps { children, .. }: &TabPanelProps) -> Html {
+    html! { for children.iter() }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct TabsProps {
+    pub children: ChildrenWithProps<TabPanel>,
+
+    /// Look of the tabs
+    ///  default is `TabsVariant::Underline`
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Controlled index of the selected tab, selecting a tab
+    ///  only requests a change through `on_change`
+    #[prop_or_default]
+    pub selected: Option<usize>,
+
+    /// Called with the index of the selected tab
+    #[prop_or_default]
+    pub on_change: Callback<usize>,
+
+    /// Mount panels the first time their tab is selected instead of all at once,
+    ///  they stay mounted afterwards to keep their state
+    #[prop_or_default]
+    pub lazy: bool,
+
+    /// Accessible name of the list of tabs
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// Additional classes on top of the wrapping element
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Tabs component
+/// Switches between [`TabPanel`]s. The tabs scroll horizontally when they don't fit,
+///  and the arrow keys, Home and End move between them.
+///
+/// For tabs which change the route, see [`RoutedTabs`].
+///
+/// See [TabsProps](TabsProps) for more details.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::{components::{TabPanel, Tabs, TabsVariant}, Icon};
+///
+/// #[function_component(TabsExample)]
+/// fn tabs_example() -> Html {
+///     html! {
+///         <Tabs variant={TabsVariant::Pill} lazy={true} label="Project">
+///             <TabPanel title="Overview" icon={Icon::HOUSE}>{"Overview"}</TabPanel>
+///             <TabPanel title="Issues" badge="12">{"Issues"}</TabPanel>
+///             <TabPanel title="Settings" disabled={true}>{"Settings"}</TabPanel>
+///         </Tabs>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Tabs(
+    TabsProps {
+        children,
+        variant,
+        selected,
+        on_change,
+        lazy,
+        label,
+        class,
+    }: &TabsProps,
+) -> Html {
+    let id = use_memo((), |_| format!("mm-tabs-{}", Uuid::new_v4()));
+    let selected_state = use_state(|| None::<usize>);
+    // Panels mounted so far, in lazy mode
+    let visited = use_mut_ref(HashSet::<usize>::new);
+
+    let enabled = children
+        .iter()
+        .enumerate()
+        .filter(|(_, panel)| !panel.props.disabled)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    let current = selected
+        .or(*selected_state)
+        .or(enabled.first().copied())
+        .unwrap_or_default();
+    visited.borrow_mut().insert(current);
+
+    let tab_id = {
+        let id = id.clone();
+        move |index: usize| format!("{id}-tab-{index}")
+    };
+    let panel_id = {
+        let id = id.clone();
+        move |index: usize| format!("{id}-panel-{index}")
+    };
+
+    let change = {
+        let selected_state = selected_state.clone();
+        let on_change = on_change.clone();
+
+        Callback::from(move |index: usize| {
+            if index != current {
+                selected_state.set(Some(index));
+                on_change.emit(index);
+            }
+        })
+    };
+
+    let onkeydown = {
+        let change = change.clone();
+        let tab_id = tab_id.clone();
+        let len = children.len();
+
+        Callback::from(move |event: KeyboardEvent| {
+            if let Some(index) = step(&event.key(), len, current, |index| enabled.contains(&index))
+            {
+                event.prevent_default();
+                change.emit(index);
+
+                // Focusing the tab also scrolls it into view
+                if let Some(tab) = document()
+                    .get_element_by_id(&tab_id(index))
+                    .and_then(|tab| tab.dyn_into::<HtmlElement>().ok())
+                {
+                    let _ = tab.focus();
+                }
+            }
+        })
+    };
+
+    let (list_classes, tab_classes, active_classes, inactive_classes) = variant_classes(*variant);
+
+    let tabs = children.iter().enumerate().map(|(index, panel)| {
+        let props = &panel.props;
+        let is_selected = index == current;
+        let onclick = change.reform(move |_: MouseEvent| index);
+
+        html! {
+            <button
+                type="button"
+                role="tab"
+                id={tab_id(index)}
+                aria-selected={is_selected.to_string()}
+                aria-controls={panel_id(index)}
+                tabindex={if is_selected { "0" } else { "-1" }}
+                disabled={props.disabled}
+                class={classes!(tab_classes.clone(), if is_selected { active_classes.clone() } else { inactive_classes.clone() })}
+                {onclick}
+            >
+                { tab_content(props.icon, &props.title, props.badge.as_ref()) }
+            </button>
+        }
+    });
+
+    let visited = visited.borrow();
+    let panels = children.iter().enumerate().map(|(index, panel)| {
+        let is_selected = index == current;
+
+        html! {
+            <div
+                role="tabpanel"
+                id={panel_id(index)}
+                aria-labelledby={tab_id(index)}
+                tabindex="0"
+                hidden={!is_selected}
+                class="mm-outline-none focus-visible:mm-ring-2 focus-visible:mm-ring-primary-500"
+            >
+                if !lazy || visited.contains(&index) {
+                    { panel }
+                }
+            </div>
+        }
+    });
+
+    html! {
+        <div class={classes!("mm-flex", "mm-flex-col", "mm-gap-4", class.clone())}>
+            <div
+                role="tablist"
+                aria-label={label.clone()}
+                class={classes!("mm-flex", "mm-overflow-x-auto", list_classes)}
+                {onkeydown}
+            >
+                { for tabs }
+            </div>
+            { for panels }
+        </div>
+    }
+}
+
+/// Tab of [`RoutedTabs`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoutedTab<T>
+where
+    T: Target,
+{
+    /// The route the tab links to, the tab is selected while it is active.
+    pub route: T,
+
+    pub title: AttrValue,
+    pub icon: Option<Icon>,
+
+    /// Small counter or label after the title, e.g. a number of comments.
+    pub badge: Option<AttrValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct RoutedTabsProps<T>
+where
+    T: Target,
+{
+    /// The tabs, in order
+    pub tabs: Vec<RoutedTab<T>>,
+
+    /// Look of the tabs
+    ///  default is `TabsVariant::Underline`
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Accessible name of the navigation
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// Additional classes on top of the list of tabs
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # RoutedTabs component
+/// Tabs linking to routes, so each tab can be bookmarked and shared.
+///  The active route is shown as the selected tab, the same way the
+///  [`Header`](super::Header) highlights its links. Its panels are the
+///  content rendered by the router.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use ui_common::components::{RoutedTab, RoutedTabs};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum ProjectRoute {
+///     Overview,
+///     Issues,
+/// }
+///
+/// #[function_component(ProjectTabs)]
+/// fn project_tabs() -> Html {
+///     let tabs = vec![
+///         RoutedTab { route: ProjectRoute::Overview, title: "Overview".into(), icon: None, badge: None },
+///         RoutedTab { route: ProjectRoute::Issues, title: "Issues".into(), icon: None, badge: Some("12".into()) },
+///     ];
+///
+///     html! { <RoutedTabs<ProjectRoute> {tabs} label="Project" /> }
+/// }
+/// ```
+#[function_component]
+pub fn RoutedTabs<T>(
+    RoutedTabsProps {
+        tabs,
+        variant,
+        label,
+        class,
+    }: &RoutedTabsProps<T>,
+) -> Html
+where
+    T: Target,
+{
+    let list_ref = use_node_ref();
+    let (list_classes, tab_classes, active_classes, inactive_classes) = variant_classes(*variant);
+
+    // The arrow keys move the focus between the links, Enter follows one
+    let onkeydown = {
+        let list_ref = list_ref.clone();
+
+        Callback::from(move |event: KeyboardEvent| {
+            let Some(list) = list_ref.cast::<Element>() else {
+                return;
+            };
+            let links = focusable(&list);
+            let current = document().active_element().and_then(|active| {
+                links
+                    .iter()
+                    .position(|link| link.is_same_node(Some(&active)))
+            });
+
+            if let Some(link) = current
+                .and_then(|current| step(&event.key(), links.len(), current, |_| true))
+                .and_then(|index| links.get(index))
+            {
+                event.prevent_default();
+                let _ = link.focus();
+            }
+        })
+    };
+
+    let tabs = tabs.iter().map(|tab| {
+        html! {
+            <li class="mm-flex">
+                <Link<T>
+                    to={tab.route.clone()}
+                    class={tab_classes.clone()}
+                    active={active_classes.clone()}
+                    inactive={inactive_classes.clone()}
+                >
+                    { tab_content(tab.icon, &tab.title, tab.badge.as_ref()) }
+                </Link<T>>
+            </li>
+        }
+    });
+
+    html! {
+        <nav aria-label={label.clone()}>
+            <ul
+                class={classes!("mm-flex", "mm-overflow-x-auto", list_classes, class.clone())}
+                ref={list_ref}
+                {onkeydown}
+            >
+                { for tabs }
+            </ul>
+        </nav>
+    }
+}
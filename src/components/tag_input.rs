@@ -0,0 +1,407 @@
+use uuid::Uuid;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_hooks::use_click_away;
+
+use super::{
+    chip::Chip,
+    input::{field_classes, Size, Variant},
+    listbox::{option_id, step, Listbox, SelectOption},
+};
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// Current tags.
+    #[prop_or_default]
+    pub value: Vec<AttrValue>,
+
+    /// Emits the tags after every change.
+    #[prop_or_default]
+    pub onchange: Callback<Vec<AttrValue>>,
+
+    /// Returns an error message for the entries which can't be added.
+    #[prop_or_default]
+    pub validate: Option<Callback<AttrValue, Option<AttrValue>>>,
+
+    /// Tags suggested while typing.
+    #[prop_or_default]
+    pub suggestions: Vec<AttrValue>,
+
+    /// Only allow tags from `suggestions`.
+    #[prop_or_default]
+    pub restrict_to_suggestions: bool,
+
+    /// Treat tags differing only by case as duplicates.
+    #[prop_or(true)]
+    pub ignore_case: bool,
+
+    /// Maximum number of tags.
+    #[prop_or_default]
+    pub max: Option<usize>,
+
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+
+    /// Name of the form field, submitted once per tag.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    /// Variant of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Size of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub size: Size,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # TagInput component
+/// Free text field which turns entries into removable chips on Enter or comma.
+///  Pasted text is split on commas and new lines.
+///
+/// ## Example (email recipients)
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::TagInput;
+///
+/// #[function_component(TagInputExample)]
+/// fn tag_input_example() -> Html {
+///     let recipients = use_state(Vec::<AttrValue>::new);
+///     let onchange = {
+///         let recipients = recipients.clone();
+///         Callback::from(move |value| recipients.set(value))
+///     };
+///
+///     let validate = Callback::from(|email: AttrValue| {
+///         (!email.contains('@')).then(|| AttrValue::from("Enter a valid email address"))
+///     });
+///
+///     html! {
+///         <TagInput
+///             value={(*recipients).clone()}
+///             {onchange}
+///             {validate}
+///             suggestions={vec![AttrValue::from("team@example.com")]}
+///             placeholder="Recipients"
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn TagInput(props: &Props) -> Html {
+    let id = use_memo((), |_| format!("mm-tag-input-{}", Uuid::new_v4()));
+    let listbox_id = format!("{id}-listbox");
+    let error_id = format!("{id}-error");
+
+    let open = use_state(|| false);
+    let active = use_state(|| None::<usize>);
+    let query = use_state(String::new);
+    let error = use_state(|| None::<AttrValue>);
+
+    let node = use_node_ref();
+    let input_ref = use_node_ref();
+
+    use_click_away(node.clone(), {
+        let open = open.clone();
+
+        move |_event: Event| {
+            open.set(false);
+        }
+    });
+
+    let ignore_case = props.ignore_case;
+    let same = move |a: &str, b: &str| match ignore_case {
+        true => a.to_lowercase() == b.to_lowercase(),
+        false => a == b,
+    };
+
+    let options = {
+        let needle = query.trim().to_lowercase();
+
+        props
+            .suggestions
+            .iter()
+            .filter(|suggestion| suggestion.to_lowercase().contains(&needle))
+            .filter(|suggestion| !props.value.iter().any(|tag| same(tag, suggestion)))
+            .map(|suggestion| SelectOption::new(suggestion.clone(), suggestion.clone()))
+            .collect::<Vec<_>>()
+    };
+
+    // Adds the entries and returns the text which couldn't be added
+    let add = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+        let validate = props.validate.clone();
+        let suggestions = props.suggestions.clone();
+        let restrict = props.restrict_to_suggestions;
+        let max = props.max;
+        let error = error.clone();
+
+        move |entries: Vec<String>| -> String {
+            let mut tags = value.clone();
+            let mut rejected = Vec::new();
+            let mut message = None;
+
+            for entry in entries.iter().map(|entry| entry.trim()) {
+                if entry.is_empty() {
+                    continue;
+                }
+
+                let problem = if max.is_some_and(|max| tags.len() >= max) {
+                    Some(AttrValue::from("Maximum number of entries reached"))
+                } else if tags.iter().any(|tag| same(tag, entry)) {
+                    Some(AttrValue::from(format!("{entry} was already added")))
+                } else if restrict && !suggestions.iter().any(|suggestion| same(suggestion, entry))
+                {
+                    Some(AttrValue::from(format!("{entry} is not allowed")))
+                } else {
+                    validate
+                        .as_ref()
+                        .and_then(|validate| validate.emit(AttrValue::from(entry.to_string())))
+                };
+
+                match problem {
+                    Some(problem) => {
+                        message.get_or_insert(problem);
+                        rejected.push(entry);
+                    }
+                    None => tags.push(AttrValue::from(entry.to_string())),
+                }
+            }
+
+            if tags.len() != value.len() {
+                onchange.emit(tags);
+            }
+            error.set(message);
+
+            rejected.join(", ")
+        }
+    };
+
+    let remove = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+        let error = error.clone();
+
+        Callback::from(move |index: usize| {
+            let mut value = value.clone();
+            if index < value.len() {
+                value.remove(index);
+                error.set(None);
+                onchange.emit(value);
+            }
+        })
+    };
+
+    let onselect = {
+        let options = options.clone();
+        let add = add.clone();
+        let query = query.clone();
+        let active = active.clone();
+
+        Callback::from(move |index: usize| {
+            if let Some(option) = options.get(index) {
+                query.set(add(vec![option.value.to_string()]));
+                active.set(None);
+            }
+        })
+    };
+
+    let onhover = {
+        let active = active.clone();
+
+        Callback::from(move |index| active.set(Some(index)))
+    };
+
+    let oninput = {
+        let query = query.clone();
+        let open = open.clone();
+        let active = active.clone();
+        let error = error.clone();
+        let add = add.clone();
+
+        move |event: InputEvent| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+            let text = input.value();
+
+            active.set(None);
+            open.set(true);
+
+            // Everything before the last separator is complete, e.g. after typing a comma or pasting
+            match text.rfind([',', '\n']) {
+                Some(position) => {
+                    let entries = text[..position]
+                        .split([',', '\n'])
+                        .map(String::from)
+                        .collect::<Vec<_>>();
+                    let rest = text[position + 1..].trim_start();
+                    let rejected = add(entries);
+
+                    query.set(match rejected.is_empty() {
+                        true => rest.to_string(),
+                        false => format!("{rejected}, {rest}").trim_end().to_string(),
+                    });
+                }
+                None => {
+                    error.set(None);
+                    query.set(text);
+                }
+            }
+        }
+    };
+
+    let onfocus = {
+        let open = open.clone();
+        let disabled = props.disabled;
+
+        move |_| {
+            if !disabled {
+                open.set(true);
+            }
+        }
+    };
+
+    let onkeydown = {
+        let open = open.clone();
+        let active = active.clone();
+        let query = query.clone();
+        let options = options.clone();
+        let add = add.clone();
+        let remove = remove.clone();
+        let onselect = onselect.clone();
+        let count = props.value.len();
+
+        move |event: KeyboardEvent| {
+            let key = event.key();
+            match key.as_str() {
+                "ArrowDown" | "ArrowUp" if !options.is_empty() => {
+                    event.prevent_default();
+                    open.set(true);
+                    active.set(step(&options, *active, key == "ArrowDown"));
+                }
+                "Enter" => {
+                    match (*open, *active) {
+                        (true, Some(index)) => onselect.emit(index),
+                        _ if !query.trim().is_empty() => query.set(add(vec![(*query).clone()])),
+                        // Let an empty field submit the form
+                        _ => return,
+                    }
+                    event.prevent_default();
+                }
+                "Escape" if *open => {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    open.set(false);
+                }
+                "Backspace" if query.is_empty() && count > 0 => {
+                    remove.emit(count - 1);
+                }
+                "Tab" => open.set(false),
+                _ => {}
+            }
+        }
+    };
+
+    let focus_input = {
+        let input_ref = input_ref.clone();
+
+        move |_| {
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                let _ = input.focus();
+            }
+        }
+    };
+
+    let chips = props
+        .value
+        .iter()
+        .enumerate()
+        .map(|(index, tag)| {
+            let onremove = {
+                let remove = remove.clone();
+
+                Callback::from(move |_| remove.emit(index))
+            };
+
+            html! {
+                <Chip label={tag.clone()} {onremove} disabled={props.disabled} />
+            }
+        })
+        .collect::<Html>();
+
+    let class = classes!(
+        field_classes(&props.variant, &props.size),
+        "mm-flex",
+        "mm-flex-wrap",
+        "mm-items-center",
+        "mm-gap-1",
+        "mm-cursor-text",
+        "focus-within:mm-border-transparent-black-800",
+        "dark:focus-within:mm-border-transparent-white-800",
+        error
+            .is_some()
+            .then_some("!mm-border-red-600 dark:!mm-border-red-500"),
+        props
+            .disabled
+            .then_some("mm-bg-gray-high-300 dark:mm-bg-gray-low-900 mm-cursor-not-allowed"),
+        props.class.clone(),
+    );
+
+    let full = props.max.is_some_and(|max| props.value.len() >= max);
+    let show_suggestions = *open && !options.is_empty();
+
+    html! {
+        <div class="mm-relative mm-w-full" ref={node}>
+            <div {class} onclick={focus_input}>
+                { chips }
+                <input
+                    ref={input_ref}
+                    id={(*id).clone()}
+                    class="mm-flex-1 mm-min-w-16 mm-bg-transparent mm-outline-none placeholder:mm-text-gray-high-900 placeholder:dark:mm-text-gray-low-400 disabled:mm-cursor-not-allowed"
+                    type="text"
+                    role="combobox"
+                    autocomplete="off"
+                    aria-autocomplete="list"
+                    aria-expanded={show_suggestions.to_string()}
+                    aria-controls={listbox_id.clone()}
+                    aria-activedescendant={show_suggestions.then(|| (*active).map(|index| option_id(&listbox_id, index))).flatten()}
+                    aria-invalid={error.is_some().to_string()}
+                    aria-describedby={error.is_some().then(|| error_id.clone())}
+                    placeholder={(!full).then(|| props.placeholder.clone())}
+                    value={(*query).clone()}
+                    disabled={props.disabled}
+                    {oninput}
+                    {onfocus}
+                    {onkeydown}
+                />
+            </div>
+            if let Some(error) = &*error {
+                <p id={error_id} class="mm-mt-1 mm-text-sm mm-text-red-600 dark:mm-text-red-500" role="alert">
+                    { error.clone() }
+                </p>
+            }
+            if let Some(name) = &props.name {
+                { for props.value.iter().map(|tag| html! {
+                    <input type="hidden" name={name.clone()} value={tag.clone()} />
+                }) }
+            }
+            if show_suggestions {
+                <Listbox<AttrValue>
+                    id={listbox_id}
+                    {options}
+                    selected={Vec::new()}
+                    active={*active}
+                    {onselect}
+                    {onhover}
+                />
+            }
+        </div>
+    }
+}
@@ -0,0 +1,313 @@
+use chrono::{DateTime, NaiveTime, TimeZone, Timelike};
+use uuid::Uuid;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_hooks::use_click_away;
+
+use super::{
+    calendar::parse_time,
+    input::{field_classes, Size, Variant},
+    listbox::{option_id, step, Listbox, SelectOption},
+};
+use crate::Icon;
+
+#[derive(Properties)]
+pub struct Props<Tz>
+where
+    Tz: TimeZone,
+{
+    /// Date and time whose time of day is edited, the date and the time zone are kept.
+    pub value: DateTime<Tz>,
+
+    /// Emits the value with the new time of day.
+    #[prop_or_default]
+    pub onchange: Callback<DateTime<Tz>>,
+
+    /// Minutes between the suggested times.
+    #[prop_or(15)]
+    pub step_minutes: u32,
+
+    /// `chrono` format of the displayed time.
+    #[prop_or(AttrValue::from("%H:%M"))]
+    pub format: AttrValue,
+
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+
+    /// Name of the form field, submitted as an RFC 3339 date and time.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+
+    /// Variant of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Size of the field, shared with [`Input`](super::Input).
+    #[prop_or_default]
+    pub size: Size,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// List of classes to apply.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+// Not derived, so `Tz` itself doesn't have to implement `PartialEq` (e.g. `chrono::Local`)
+impl<Tz> PartialEq for Props<Tz>
+where
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.onchange == other.onchange
+            && self.step_minutes == other.step_minutes
+            && self.format == other.format
+            && self.placeholder == other.placeholder
+            && self.name == other.name
+            && self.variant == other.variant
+            && self.size == other.size
+            && self.disabled == other.disabled
+            && self.class == other.class
+    }
+}
+
+/// Replaces the time of day, keeping the date and the time zone.
+///
+/// Returns `None` when the time doesn't exist on that date, e.g. inside a DST gap.
+fn with_time<Tz>(value: &DateTime<Tz>, time: NaiveTime) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+{
+    value
+        .timezone()
+        .from_local_datetime(&value.date_naive().and_time(time))
+        .earliest()
+}
+
+/// # TimePicker component
+/// Text field with a list of suggested times. Typed times are parsed leniently
+///  (see [`parse_time`](super::parse_time)) when the field loses focus or on Enter.
+///
+/// ## Example
+/// ```rust
+/// use chrono::{DateTime, Local};
+/// use yew::prelude::*;
+/// use ui_common::components::TimePicker;
+///
+/// #[function_component(TimePickerExample)]
+/// fn time_picker_example() -> Html {
+///     let starts_at = use_state(Local::now);
+///     let onchange = {
+///         let starts_at = starts_at.clone();
+///         Callback::from(move |value: DateTime<Local>| starts_at.set(value))
+///     };
+///
+///     html! {
+///         <TimePicker<Local> value={*starts_at} {onchange} step_minutes={30} />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn TimePicker<Tz>(props: &Props<Tz>) -> Html
+where
+    Tz: TimeZone + 'static,
+{
+    let id = use_memo((), |_| format!("mm-time-picker-{}", Uuid::new_v4()));
+    let listbox_id = format!("{id}-listbox");
+
+    let open = use_state(|| false);
+    let active = use_state(|| None::<usize>);
+    let text = use_state(String::new);
+    let node = use_node_ref();
+
+    use_click_away(node.clone(), {
+        let open = open.clone();
+
+        move |_event: Event| {
+            open.set(false);
+        }
+    });
+
+    let time = props.value.time();
+
+    use_effect_with((time, props.format.clone()), {
+        let text = text.clone();
+
+        move |(time, format)| text.set(time.format(format).to_string())
+    });
+
+    let options = use_memo(
+        (props.step_minutes, props.format.clone()),
+        |(step, format)| {
+            (0..24 * 60)
+                .step_by((*step).clamp(1, 24 * 60) as usize)
+                .filter_map(|minutes| NaiveTime::from_hms_opt(minutes / 60, minutes % 60, 0))
+                .map(|time| SelectOption::new(time, time.format(format).to_string()))
+                .collect::<Vec<_>>()
+        },
+    );
+
+    // The suggestion closest to the current value
+    let closest = options
+        .iter()
+        .rposition(|option| option.value <= time.with_second(0).unwrap_or(time));
+
+    let emit = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+
+        move |time: NaiveTime| {
+            if let Some(value) = with_time(&value, time) {
+                onchange.emit(value);
+            }
+        }
+    };
+
+    let select = {
+        let open = open.clone();
+        let options = options.clone();
+        let emit = emit.clone();
+
+        Callback::from(move |index: usize| {
+            if let Some(option) = options.get(index) {
+                emit(option.value);
+            }
+            open.set(false);
+        })
+    };
+
+    let commit = {
+        let text = text.clone();
+        let format = props.format.clone();
+
+        move || match parse_time(&text) {
+            Some(parsed) if parsed != time => emit(parsed),
+            // Restore the current value when the text can't be parsed
+            _ => text.set(time.format(&format).to_string()),
+        }
+    };
+
+    let oninput = {
+        let text = text.clone();
+        let active = active.clone();
+
+        move |event: InputEvent| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+
+            // Enter commits the typed text instead of a suggestion
+            active.set(None);
+            text.set(input.value());
+        }
+    };
+
+    let onfocus = {
+        let open = open.clone();
+        let active = active.clone();
+
+        move |_| {
+            active.set(closest);
+            open.set(true);
+        }
+    };
+
+    let onblur = {
+        let commit = commit.clone();
+        let open = open.clone();
+
+        move |_| {
+            commit();
+            open.set(false);
+        }
+    };
+
+    let onkeydown = {
+        let open = open.clone();
+        let active = active.clone();
+        let select = select.clone();
+        let options = options.clone();
+
+        move |event: KeyboardEvent| {
+            let key = event.key();
+            match key.as_str() {
+                "ArrowDown" | "ArrowUp" => {
+                    event.prevent_default();
+                    open.set(true);
+                    active.set(step(&options, *active, key == "ArrowDown"));
+                }
+                "Enter" => {
+                    event.prevent_default();
+                    match (*open, *active) {
+                        (true, Some(index)) => select.emit(index),
+                        _ => commit(),
+                    }
+                    open.set(false);
+                }
+                "Escape" if *open => {
+                    event.stop_propagation();
+                    open.set(false);
+                }
+                _ => {}
+            }
+        }
+    };
+
+    let onhover = {
+        let active = active.clone();
+
+        Callback::from(move |index| active.set(Some(index)))
+    };
+
+    let class = classes!(
+        field_classes(&props.variant, &props.size),
+        "mm-flex",
+        "mm-items-center",
+        "mm-gap-2",
+        "focus-within:mm-border-transparent-black-800",
+        "dark:focus-within:mm-border-transparent-white-800",
+        props
+            .disabled
+            .then_some("mm-bg-gray-high-300 dark:mm-bg-gray-low-900 mm-cursor-not-allowed"),
+        props.class.clone(),
+    );
+
+    html! {
+        <div class="mm-relative mm-w-full" ref={node}>
+            <div {class}>
+                <input
+                    id={(*id).clone()}
+                    class="mm-flex-1 mm-min-w-0 mm-bg-transparent mm-outline-none placeholder:mm-text-gray-high-900 placeholder:dark:mm-text-gray-low-400 disabled:mm-cursor-not-allowed"
+                    type="text"
+                    role="combobox"
+                    autocomplete="off"
+                    aria-autocomplete="list"
+                    aria-expanded={open.to_string()}
+                    aria-controls={listbox_id.clone()}
+                    aria-activedescendant={(*open).then(|| (*active).map(|index| option_id(&listbox_id, index))).flatten()}
+                    placeholder={props.placeholder.clone()}
+                    value={(*text).clone()}
+                    disabled={props.disabled}
+                    {oninput}
+                    {onfocus}
+                    {onblur}
+                    {onkeydown}
+                />
+                <span class="mm-inline-flex mm-items-center mm-shrink-0 mm-text-xl mm-text-gray-low-100 dark:mm-text-gray-low-200">{ Icon::CLOCK }</span>
+            </div>
+            if let Some(name) = &props.name {
+                <input type="hidden" name={name.clone()} value={props.value.fixed_offset().to_rfc3339()} />
+            }
+            if *open {
+                <Listbox<NaiveTime>
+                    id={listbox_id}
+                    options={(*options).clone()}
+                    selected={vec![time]}
+                    active={*active}
+                    onselect={select}
+                    {onhover}
+                />
+            }
+        </div>
+    }
+}
@@ -0,0 +1,545 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gloo_timers::callback::Timeout;
+use gloo_utils::body;
+use yew::prelude::*;
+use yew_hooks::{use_swipe_with_options, UseSwipeDirection, UseSwipeOptions};
+
+use super::{
+    button::{Button, Color, NoRoute, Size, Variant},
+    overlay::TOAST_Z_INDEX,
+    transition::use_transition,
+};
+use crate::Icon;
+
+// How long a toast stays on screen by default, in milliseconds
+const DEFAULT_DURATION: u32 = 5000;
+
+// How many pixels a toast has to be swiped sideways to be dismissed
+const SWIPE_THRESHOLD: i32 = 80;
+
+// Length of the enter and exit transitions in milliseconds, matches `mm-duration-200`
+const TRANSITION_DURATION: u32 = 200;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Kind {
+    Success,
+    Error,
+    Info,
+    Warning,
+}
+
+#[derive(PartialEq, Default, Clone, Copy, Debug)]
+pub enum Position {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    #[default]
+    BottomRight,
+}
+
+/// Button shown in a toast, e.g. "Undo". Clicking it also dismisses the toast.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastAction {
+    pub label: AttrValue,
+    pub onclick: Callback<()>,
+}
+
+impl ToastAction {
+    pub fn new(label: impl Into<AttrValue>, onclick: Callback<()>) -> Self {
+        Self {
+            label: label.into(),
+            onclick,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastOptions {
+    pub message: AttrValue,
+
+    /// Secondary text under the message.
+    pub description: Option<AttrValue>,
+
+    /// Milliseconds before the toast is dismissed, `0` keeps it until the user dismisses it.
+    pub duration: u32,
+
+    pub action: Option<ToastAction>,
+}
+
+impl Default for ToastOptions {
+    fn default() -> Self {
+        Self {
+            message: AttrValue::default(),
+            description: None,
+            duration: DEFAULT_DURATION,
+            action: None,
+        }
+    }
+}
+
+impl From<&'static str> for ToastOptions {
+    fn from(message: &'static str) -> Self {
+        AttrValue::from(message).into()
+    }
+}
+
+impl From<String> for ToastOptions {
+    fn from(message: String) -> Self {
+        AttrValue::from(message).into()
+    }
+}
+
+impl From<AttrValue> for ToastOptions {
+    fn from(message: AttrValue) -> Self {
+        Self {
+            message,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct Entry {
+    id: usize,
+    kind: Kind,
+    options: ToastOptions,
+    /// Dismissed toasts stay until their exit transition ends.
+    dismissed: bool,
+}
+
+enum Action {
+    Push(Entry),
+    Dismiss(usize),
+    Remove(usize),
+}
+
+#[derive(Default, PartialEq)]
+struct Toasts {
+    entries: Vec<Entry>,
+}
+
+impl Reducible for Toasts {
+    type Action = Action;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut entries = self.entries.clone();
+
+        match action {
+            Action::Push(entry) => entries.push(entry),
+            Action::Dismiss(id) => {
+                if let Some(entry) = entries.iter_mut().find(|entry| entry.id == id) {
+                    entry.dismissed = true;
+                }
+            }
+            Action::Remove(id) => entries.retain(|entry| entry.id != id),
+        }
+
+        Rc::new(Self { entries })
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct UseToastsHandle {
+    dispatch: UseReducerDispatcher<Toasts>,
+    next_id: Rc<RefCell<usize>>,
+}
+
+impl UseToastsHandle {
+    /// Shows a toast and returns its id, to dismiss it with [`dismiss`](Self::dismiss).
+    pub fn show(&self, kind: Kind, options: impl Into<ToastOptions>) -> usize {
+        let id = {
+            let mut next_id = self.next_id.borrow_mut();
+            *next_id += 1;
+            *next_id
+        };
+
+        self.dispatch.dispatch(Action::Push(Entry {
+            id,
+            kind,
+            options: options.into(),
+            dismissed: false,
+        }));
+
+        id
+    }
+
+    pub fn success(&self, options: impl Into<ToastOptions>) -> usize {
+        self.show(Kind::Success, options)
+    }
+
+    pub fn error(&self, options: impl Into<ToastOptions>) -> usize {
+        self.show(Kind::Error, options)
+    }
+
+    pub fn info(&self, options: impl Into<ToastOptions>) -> usize {
+        self.show(Kind::Info, options)
+    }
+
+    pub fn warning(&self, options: impl Into<ToastOptions>) -> usize {
+        self.show(Kind::Warning, options)
+    }
+
+    pub fn dismiss(&self, id: usize) {
+        self.dispatch.dispatch(Action::Dismiss(id));
+    }
+}
+
+/// # use_toasts hook
+/// Shows short messages such as "Saved" or "Failed to load".
+///  Must be used inside a [`ToastProvider`].
+///
+/// A string is enough for a simple message, [`ToastOptions`] add a description,
+///  an action button or a custom duration.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{use_toasts, ToastAction, ToastOptions};
+///
+/// #[function_component(SaveButton)]
+/// fn save_button() -> Html {
+///     let toasts = use_toasts();
+///
+///     let onclick = Callback::from(move |_| {
+///         toasts.success("Saved");
+///         toasts.error(ToastOptions {
+///             message: "Failed to load the comments".into(),
+///             action: Some(ToastAction::new("Retry", Callback::from(|_| ()))),
+///             duration: 0,
+///             ..ToastOptions::default()
+///         });
+///     });
+///
+///     html! { <button {onclick}>{"Save"}</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_toasts() -> UseToastsHandle {
+    use_context::<UseToastsHandle>().expect("use_toasts must be used inside a ToastProvider")
+}
+
+#[derive(PartialEq, Properties)]
+pub struct ToastProviderProps {
+    pub children: Children,
+
+    /// Corner or edge of the screen where the toasts stack
+    ///  default is `ToastPosition::BottomRight`
+    #[prop_or_default]
+    pub position: Position,
+
+    /// How many toasts are visible at once, the next ones wait for a free place
+    ///  default is 3
+    #[prop_or(3)]
+    pub limit: usize,
+}
+
+/// # ToastProvider component
+/// Shows the toasts requested through [`use_toasts`], stacked in a corner or
+///  at the top or bottom centre of the screen. Place it once near the root of the application.
+///
+/// Toasts are dismissed after their duration, which pauses while the pointer
+///  is over them, with their close button, or by swiping them sideways.
+///  They are rendered into `body` like a [`Modal`](super::Modal), above it.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{ToastPosition, ToastProvider};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ToastProvider position={ToastPosition::TopCenter} limit={5}>
+///             <main>{"Application"}</main>
+///         </ToastProvider>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ToastProvider(
+    ToastProviderProps {
+        children,
+        position,
+        limit,
+    }: &ToastProviderProps,
+) -> Html {
+    let toasts = use_reducer(Toasts::default);
+    let next_id = use_mut_ref(|| 0);
+    let handle = UseToastsHandle {
+        dispatch: toasts.dispatcher(),
+        next_id,
+    };
+
+    let position_classes = match position {
+        Position::TopLeft => classes!("mm-top-0", "mm-left-0", "mm-flex-col"),
+        Position::TopCenter => classes!(
+            "mm-top-0",
+            "mm-left-1/2",
+            "-mm-translate-x-1/2",
+            "mm-flex-col"
+        ),
+        Position::TopRight => classes!("mm-top-0", "mm-right-0", "mm-flex-col"),
+        Position::BottomLeft => classes!("mm-bottom-0", "mm-left-0", "mm-flex-col-reverse"),
+        Position::BottomCenter => classes!(
+            "mm-bottom-0",
+            "mm-left-1/2",
+            "-mm-translate-x-1/2",
+            "mm-flex-col-reverse"
+        ),
+        Position::BottomRight => classes!("mm-bottom-0", "mm-right-0", "mm-flex-col-reverse"),
+    };
+
+    let toasts = toasts.entries.iter().take(*limit).map(|entry| {
+        let id = entry.id;
+        let ondismiss = {
+            let dispatch = handle.dispatch.clone();
+            Callback::from(move |_| dispatch.dispatch(Action::Dismiss(id)))
+        };
+        let onremoved = {
+            let dispatch = handle.dispatch.clone();
+            Callback::from(move |_| dispatch.dispatch(Action::Remove(id)))
+        };
+
+        html! {
+            <Toast
+                key={id}
+                kind={entry.kind}
+                options={entry.options.clone()}
+                dismissed={entry.dismissed}
+                {ondismiss}
+                {onremoved}
+            />
+        }
+    });
+
+    // The region is always rendered, screen readers only announce changes of existing regions
+    let region = create_portal(
+        html! {
+            <section
+                aria-label="Notifications"
+                class={classes!("mm-fixed", "mm-flex", "mm-gap-2", "mm-p-4", "mm-w-full", "mm-max-w-sm", "mm-pointer-events-none", position_classes)}
+                style={format!("z-index: {TOAST_Z_INDEX}")}
+            >
+                { for toasts }
+            </section>
+        },
+        body().into(),
+    );
+
+    html! {
+        <ContextProvider<UseToastsHandle> context={handle}>
+            { for children.iter() }
+            { region }
+        </ContextProvider<UseToastsHandle>>
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct ToastProps {
+    kind: Kind,
+    options: ToastOptions,
+    dismissed: bool,
+    ondismiss: Callback<()>,
+    /// Called once the exit transition is over.
+    onremoved: Callback<()>,
+}
+
+#[function_component]
+fn Toast(
+    ToastProps {
+        kind,
+        options,
+        dismissed,
+        ondismiss,
+        onremoved,
+    }: &ToastProps,
+) -> Html {
+    let transition = use_transition(!dismissed, TRANSITION_DURATION);
+    let node = use_node_ref();
+    let swipe_distance = use_state(|| 0_i32);
+
+    // Time left before the toast is dismissed, it only runs while not hovered
+    let remaining = use_mut_ref(|| options.duration as f64);
+    let started = use_mut_ref(|| 0.0);
+    let timeout = use_mut_ref(|| None::<Timeout>);
+
+    let resume = {
+        let remaining = remaining.clone();
+        let started = started.clone();
+        let timeout = timeout.clone();
+        let ondismiss = ondismiss.clone();
+        let enabled = options.duration > 0 && !dismissed;
+
+        Rc::new(move || {
+            if enabled {
+                *started.borrow_mut() = js_sys::Date::now();
+
+                let ondismiss = ondismiss.clone();
+                *timeout.borrow_mut() = Some(Timeout::new(
+                    remaining.borrow().max(0.0) as u32,
+                    move || ondismiss.emit(()),
+                ));
+            }
+        })
+    };
+
+    let pause = Rc::new(move || {
+        // Dropping the timeout cancels it
+        if timeout.borrow_mut().take().is_some() {
+            *remaining.borrow_mut() -= js_sys::Date::now() - *started.borrow();
+        }
+    });
+
+    use_effect_with(*dismissed, {
+        let resume = resume.clone();
+        let pause = pause.clone();
+
+        move |_| {
+            resume();
+            move || pause()
+        }
+    });
+
+    use_effect_with(transition.mounted, {
+        let onremoved = onremoved.clone();
+
+        move |mounted| {
+            if !mounted {
+                onremoved.emit(());
+            }
+        }
+    });
+
+    let swipe = use_swipe_with_options(
+        node.clone(),
+        UseSwipeOptions {
+            onswipeend: Some(Box::new({
+                let swipe_distance = swipe_distance.clone();
+                let ondismiss = ondismiss.clone();
+
+                move |_e, _direction| {
+                    if (*swipe_distance).abs() > SWIPE_THRESHOLD {
+                        // Leave the toast where it was swiped, it fades out from there
+                        ondismiss.emit(());
+                    } else {
+                        swipe_distance.set(0);
+                    }
+                }
+            })),
+            ..Default::default()
+        },
+    );
+
+    use_effect_with(*swipe.length_x, {
+        let swipe_distance = swipe_distance.clone();
+
+        move |length_x| {
+            if matches!(
+                *swipe.direction,
+                UseSwipeDirection::Left | UseSwipeDirection::Right
+            ) {
+                swipe_distance.set(-*length_x);
+            }
+        }
+    });
+
+    let (icon, icon_classes, role) = match kind {
+        Kind::Success => (
+            Icon::CHECK_CIRCLE,
+            classes!("mm-text-green-600", "dark:mm-text-green-500"),
+            "status",
+        ),
+        Kind::Error => (
+            Icon::X_CIRCLE,
+            classes!("mm-text-red-600", "dark:mm-text-red-500"),
+            "alert",
+        ),
+        Kind::Info => (
+            Icon::INFO,
+            classes!("mm-text-primary-700", "dark:mm-text-primary-500"),
+            "status",
+        ),
+        Kind::Warning => (
+            Icon::WARNING,
+            classes!("mm-text-amber-500", "dark:mm-text-amber-400"),
+            "alert",
+        ),
+    };
+
+    let transition_classes = if transition.shown {
+        classes!("mm-opacity-100", "mm-scale-100")
+    } else {
+        classes!("mm-opacity-0", "mm-scale-95")
+    };
+
+    // Follow the finger closely while it drags the toast
+    let duration_class = if *swipe_distance != 0 && !dismissed {
+        "mm-duration-75"
+    } else {
+        "mm-duration-200"
+    };
+
+    let style =
+        (*swipe_distance != 0).then(|| format!("transform: translateX({}px)", *swipe_distance));
+
+    let action = options.action.as_ref().map(|action| {
+        let onclick = {
+            let onclick = action.onclick.clone();
+            let ondismiss = ondismiss.clone();
+
+            Callback::from(move |_| {
+                onclick.emit(());
+                ondismiss.emit(());
+            })
+        };
+
+        html! {
+            <Button<NoRoute>
+                size={Size::Small}
+                variant={Variant::Outline}
+                color={Color::Secondary}
+                text={action.label.to_string()}
+                {onclick}
+            />
+        }
+    });
+
+    let onmouseenter = {
+        let pause = pause.clone();
+        Callback::from(move |_: MouseEvent| pause())
+    };
+    let onmouseleave = Callback::from(move |_: MouseEvent| resume());
+
+    html! {
+        <div
+            {role}
+            class={classes!("mm-pointer-events-auto", "mm-flex", "mm-items-start", "mm-gap-3", "mm-w-full", "mm-p-4", "mm-rounded-lg", "mm-shadow-lg", "mm-text-sm", "mm-bg-gray-high-200", "dark:mm-bg-gray-low-800", "mm-transition", "mm-ease-out", "motion-reduce:mm-transition-none", duration_class, transition_classes)}
+            {style}
+            ref={node}
+            {onmouseenter}
+            {onmouseleave}
+        >
+            <span class={classes!("mm-shrink-0", "mm-text-xl", "mm-flex", icon_classes)}>{ icon }</span>
+            <div class="mm-flex mm-flex-col mm-gap-1 mm-grow">
+                <p class="mm-font-medium mm-text-gray-low-800 dark:mm-text-gray-high-200">{ options.message.clone() }</p>
+                if let Some(description) = &options.description {
+                    <p class="mm-text-gray-low-100 dark:mm-text-gray-high-800">{ description.clone() }</p>
+                }
+                if let Some(action) = action {
+                    <div class="mm-pt-1">{ action }</div>
+                }
+            </div>
+            <button
+                type="button"
+                aria-label="Dismiss"
+                class="mm-shrink-0 mm-inline-flex mm-items-center mm-rounded-full mm-text-gray-low-100 dark:mm-text-gray-low-200 hover:mm-text-gray-low-800 dark:hover:mm-text-gray-high-200"
+                onclick={ondismiss.reform(|_| ())}
+            >
+                { Icon::X }
+            </button>
+        </div>
+    }
+}
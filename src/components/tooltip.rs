@@ -1,18 +1,24 @@
-use gloo_utils::window;
-use web_sys::HtmlElement;
+use std::rc::Rc;
+
+use gloo_timers::callback::Timeout;
+use gloo_utils::{body, window};
+use uuid::Uuid;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::Element;
 use yew::prelude::*;
+use yew_hooks::use_click_away;
+
+use super::{
+    floating::{use_floating, Align, Placement, Position, Side},
+    overlay::top_z_index,
+};
 
 // Describes the size of the tooltip arrow (based on the largest side)
 // Note: This variable affects the tooltip arrow size in CSS too
 //  so there is no need to change it in CSS. You can change it here
 //  and it will be applied to the CSS automatically
-const TOOLTIP_ARROW_SIZE: u8 = 8;
-
-#[derive(Debug, Clone, PartialEq, Default)]
-struct Coordinates {
-    top: f64,
-    left: f64,
-}
+// Note: The popover arrow uses the same size
+pub(crate) const TOOLTIP_ARROW_SIZE: u8 = 8;
 
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum TooltipPosition {
@@ -22,6 +28,130 @@ pub enum TooltipPosition {
     Right,
     Bottom,
     Left,
+
+    /// The side with enough room in the viewport
+    Auto,
+}
+
+impl TooltipPosition {
+    /// Preferred side for the positioning engine, `None` for `Auto`
+    pub(crate) fn side(&self) -> Option<Side> {
+        match self {
+            TooltipPosition::Top => Some(Side::Top),
+            TooltipPosition::Right => Some(Side::Right),
+            TooltipPosition::Bottom => Some(Side::Bottom),
+            TooltipPosition::Left => Some(Side::Left),
+            TooltipPosition::Auto => None,
+        }
+    }
+
+    /// Placement for the positioning engine, leaving room for the arrow
+    pub(crate) fn placement(&self, offset: u16, arrow: bool) -> Placement {
+        Placement {
+            side: self.side(),
+            align: Align::Center,
+            // If the arrow is enabled, we need to add the arrow size
+            offset: offset as f64
+                + if arrow {
+                    TOOLTIP_ARROW_SIZE as f64
+                } else {
+                    0.0
+                },
+            padding: 4.0,
+            arrow_padding: TOOLTIP_ARROW_SIZE as f64 + 4.0,
+        }
+    }
+}
+
+/// Position of the floating element along with the arrow CSS variables
+pub(crate) fn floating_style(coordinates: &Position, z_index: usize) -> String {
+    format!(
+        "top: {top}px; left: {left}px; z-index: {z_index}; --mm-tooltip-arrow-size: {size}px; --mm-tooltip-arrow-offset: {arrow_offset}px;",
+        top = coordinates.top,
+        left = coordinates.left,
+        size = TOOLTIP_ARROW_SIZE,
+        arrow_offset = coordinates.arrow
+    )
+}
+
+/// What opens the tooltip.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TooltipTrigger {
+    /// Pointer entering the element
+    Hover,
+
+    /// Keyboard focus inside the element
+    Focus,
+
+    /// Click or tap on the element, closed by clicking elsewhere
+    Click,
+
+    /// Only the `open` prop controls the tooltip
+    Manual,
+}
+
+/// Arrow classes for the side on which the tooltip ended up.
+///  The arrow is placed at `--mm-tooltip-arrow-offset` so it keeps
+///  pointing at the anchor when the tooltip is shifted along the side
+pub(crate) fn arrow_classes(side: Side) -> Classes {
+    let common_arrow_classes = classes!(
+        "before:mm-content-['']",
+        "before:mm-absolute",
+        // Apply arrow size from the CSS variable
+        //  which will be send from our Rust `TOOLTIP_ARROW_SIZE` constant
+        // Note: We use `length` postfix because `border` is an ambiguity type
+        //  and Tailwind CSS doesn know will it be a `width` or `color`
+        //  to fix it we have to provide a `length` postfix to explicitly
+        //  say Tailwind that this variable is a `width` of the border
+        //
+        // @see {@link https://tailwindcss.com/docs/adding-custom-styles#resolving-ambiguities}
+        "before:mm-border-[length:var(--mm-tooltip-arrow-size)]",
+    );
+
+    let specific_arrow_classes = match side {
+        Side::Bottom => classes!(
+            "before:mm-bottom-[100%]",
+            "before:mm-left-[var(--mm-tooltip-arrow-offset)]",
+            "before:-mm-ml-[var(--mm-tooltip-arrow-size)]",
+            "before:mm-border-t-transparent",
+            "before:mm-border-r-transparent",
+            "before:mm-border-b-gray-high-200",
+            "dark:before:mm-border-b-gray-low-800",
+            "before:mm-border-l-transparent",
+        ),
+        Side::Right => classes!(
+            "before:mm-top-[var(--mm-tooltip-arrow-offset)]",
+            "before:mm-right-[100%]",
+            "before:-mm-mt-[var(--mm-tooltip-arrow-size)]",
+            "before:mm-border-t-transparent",
+            "before:mm-border-r-gray-high-200",
+            "dark:before:mm-border-r-gray-low-800",
+            "before:mm-border-b-transparent",
+            "before:mm-border-l-transparent",
+        ),
+        Side::Top => classes!(
+            "before:mm-top-[100%]",
+            "before:mm-left-[var(--mm-tooltip-arrow-offset)]",
+            "before:-mm-ml-[var(--mm-tooltip-arrow-size)]",
+            "before:mm-border-t-gray-high-200",
+            "dark:before:mm-border-t-gray-low-800",
+            "before:mm-border-r-transparent",
+            "before:mm-border-b-transparent",
+            "before:mm-border-l-transparent",
+        ),
+        Side::Left => classes!(
+            "before:mm-top-[var(--mm-tooltip-arrow-offset)]",
+            "before:mm-left-[100%]",
+            "before:-mm-mt-[var(--mm-tooltip-arrow-size)]",
+            "before:mm-border-t-transparent",
+            "before:mm-border-r-transparent",
+            "before:mm-border-b-transparent",
+            "before:mm-border-l-gray-high-200",
+            "dark:before:mm-border-l-gray-low-800",
+        ),
+    };
+
+    classes!(common_arrow_classes, specific_arrow_classes)
 }
 
 #[derive(PartialEq, Properties)]
@@ -36,7 +166,8 @@ pub struct TooltipProps {
     #[prop_or_default]
     pub class: Classes,
 
-    /// Position of the tooltip
+    /// Position of the tooltip, flipped to the opposite side
+    ///  when there is not enough room in the viewport
     ///  default value is `TooltipPosition::Right`
     #[prop_or_default]
     pub position: TooltipPosition,
@@ -50,11 +181,41 @@ pub struct TooltipProps {
     ///  default is 8px
     #[prop_or(8)]
     pub offset: u16,
+
+    /// Events which open the tooltip
+    ///  default value is `[TooltipTrigger::Hover, TooltipTrigger::Focus]`
+    #[prop_or_else(|| vec![TooltipTrigger::Hover, TooltipTrigger::Focus])]
+    pub triggers: Vec<TooltipTrigger>,
+
+    /// Controlled visibility, the triggers only request changes through `on_open_change`
+    #[prop_or_default]
+    pub open: Option<bool>,
+
+    /// Called when a trigger, Escape or a click away opens or closes the tooltip
+    #[prop_or_default]
+    pub on_open_change: Callback<bool>,
+
+    /// Delay before showing the tooltip, in milliseconds
+    #[prop_or_default]
+    pub show_delay: u32,
+
+    /// Delay before hiding the tooltip, in milliseconds
+    #[prop_or_default]
+    pub hide_delay: u32,
 }
 
 /// # Tooltip component
 /// Tooltip component is responsible to show a tooltip
-///  on any children element when the user hovers over it.
+///  on any children element when the user hovers over it
+///  or focuses it. Escape closes it.
+///
+/// The first child element gets `aria-describedby` pointing
+///  to the tooltip, so it should be the focusable one.
+///
+/// The tooltip stays inside the viewport: it is flipped to the opposite side
+///  when there is not enough room, and shifted along the side with the arrow
+///  still pointing at the element. Use `TooltipPosition::Auto` to let it
+///  pick the side.
 ///
 /// See [TooltipProps](TooltipProps) for more details.
 ///
@@ -83,7 +244,7 @@ pub struct TooltipProps {
 ///     html! {
 ///         <Tooltip
 ///             title={"Tooltip title"}
-///             position={TooltipPosition::Bottom}
+///             position={TooltipPosition::Auto}
 ///             arrow={true}
 ///             offset={8}
 ///         >
@@ -92,6 +253,29 @@ pub struct TooltipProps {
 ///     }
 /// }
 /// ```
+///
+/// ## Example (controlled)
+/// ```rust
+/// use yew::prelude::*;
+/// use ui_common::components::{Tooltip, TooltipTrigger};
+///
+/// #[function_component(TooltipExample)]
+/// fn tooltip_example() -> Html {
+///     let open = use_state(|| false);
+///
+///     html! {
+///         <Tooltip
+///             title={"Copied!"}
+///             triggers={vec![TooltipTrigger::Click]}
+///             open={*open}
+///             on_open_change={Callback::from(move |value| open.set(value))}
+///             hide_delay={1500}
+///         >
+///             <button>{"Copy"}</button>
+///         </Tooltip>
+///     }
+/// }
+/// ```
 #[function_component]
 pub fn Tooltip(
     TooltipProps {
@@ -101,18 +285,29 @@ pub fn Tooltip(
         offset,
         position,
         children,
+        triggers,
+        open,
+        on_open_change,
+        show_delay,
+        hide_delay,
     }: &TooltipProps,
 ) -> Html {
-    let visible = use_state(|| false);
-    let coordinates = use_state(Coordinates::default);
+    let id = use_memo((), |_| format!("mm-tooltip-{}", Uuid::new_v4()));
+    let visible_state = use_state(|| false);
+    let visible = open.unwrap_or(*visible_state);
+    let anchor_ref = use_node_ref();
     let tooltip_ref = use_node_ref();
+    // Pending show or hide, dropping it cancels it
+    let timeout = use_mut_ref(|| None::<Timeout>);
+
+    let placement = position.placement(*offset, *arrow);
+    let coordinates = use_floating(anchor_ref.clone(), tooltip_ref.clone(), placement, visible);
 
     let common_tooltip_classes = classes!(
         "mm-custom-tooltip",
-        "mm-absolute",
+        "mm-fixed",
         "mm-px-3",
         "mm-py-2",
-        "mm-z-10",
         "mm-rounded",
         "mm-shadow-md",
         "mm-transition-opacity",
@@ -127,198 +322,202 @@ pub fn Tooltip(
         //  so the values will be `0`, `0` and the position
         //  of the element will be places incorrectly
         // (!*visible).then_some(Some("hidden")),
-        (!*visible).then_some(Some("mm-invisible")),
-        (!*visible).then_some(Some("mm-opacity-0")),
-        (*visible).then_some(Some("mm-opacity-100")),
+        (!visible).then_some(Some("mm-invisible")),
+        (!visible).then_some(Some("mm-opacity-0")),
+        visible.then_some(Some("mm-opacity-100")),
     );
 
-    let common_arrow_classes = classes!(
-        "before:mm-content-['']",
-        "before:mm-absolute",
-        // Apply arrow size from the CSS variable
-        //  which will be send from our Rust `TOOLTIP_ARROW_SIZE` constant
-        // Note: We use `length` postfix because `border` is an ambiguity type
-        //  and Tailwind CSS doesn know will it be a `width` or `color`
-        //  to fix it we have to provide a `length` postfix to explicitly
-        //  say Tailwind that this variable is a `width` of the border
-        //
-        // @see {@link https://tailwindcss.com/docs/adding-custom-styles#resolving-ambiguities}
-        "before:mm-border-[length:var(--mm-tooltip-arrow-size)]",
-    );
-
-    let specific_arrow_classes = match &position {
-        TooltipPosition::Bottom => classes!(
-            "before:mm-bottom-[100%]",
-            "before:mm-left-[50%]",
-            "before:-mm-ml-[var(--mm-tooltip-arrow-size)]",
-            "before:mm-border-t-transparent",
-            "before:mm-border-r-transparent",
-            "before:mm-border-b-gray-high-200",
-            "dark:before:mm-border-b-gray-low-800",
-            "before:mm-border-l-transparent",
-        ),
-        TooltipPosition::Right => classes!(
-            "before:mm-top-[50%]",
-            "before:mm-right-[100%]",
-            "before:-mm-mt-[var(--mm-tooltip-arrow-size)]",
-            "before:mm-border-t-transparent",
-            "before:mm-border-r-gray-high-200",
-            "dark:before:mm-border-r-gray-low-800",
-            "before:mm-border-b-transparent",
-            "before:mm-border-l-transparent",
-        ),
-        TooltipPosition::Top => classes!(
-            "before:mm-top-[100%]",
-            "before:mm-left-[50%]",
-            "before:-mm-ml-[var(--mm-tooltip-arrow-size)]",
-            "before:mm-border-t-gray-high-200",
-            "dark:before:mm-border-t-gray-low-800",
-            "before:mm-border-r-transparent",
-            "before:mm-border-b-transparent",
-            "before:mm-border-l-transparent",
-        ),
-        TooltipPosition::Left => classes!(
-            "before:mm-top-[50%]",
-            "before:mm-left-[100%]",
-            "before:-mm-mt-[var(--mm-tooltip-arrow-size)]",
-            "before:mm-border-t-transparent",
-            "before:mm-border-r-transparent",
-            "before:mm-border-b-transparent",
-            "before:mm-border-l-gray-high-200",
-            "dark:before:mm-border-l-gray-low-800",
-        ),
-    };
-
     let final_classes = classes!(
         common_tooltip_classes,
-        &arrow.then_some(common_arrow_classes),
-        &arrow.then_some(specific_arrow_classes),
+        arrow.then(|| arrow_classes(coordinates.side)),
     );
 
-    let on_mouse_enter = {
-        let visible = visible.clone();
-        let coordinates = coordinates.clone();
-        let tooltip_ref = tooltip_ref.clone();
-        let position = position.clone();
-        let offset = *offset as f64;
-        let arrow = *arrow;
-
-        Callback::from(move |event: MouseEvent| {
-            // HTML element on which the event was triggered
-            let html_element = event.target_unchecked_into::<HtmlElement>();
-            let html_element_rect = html_element.get_bounding_client_rect();
-
-            let tooltip_element = tooltip_ref.cast::<HtmlElement>();
-
-            if let Some(tooltip_element) = tooltip_element {
-                let tooltip_rect = tooltip_element.get_bounding_client_rect();
-
-                let tooltip_coordinates = match &position {
-                    TooltipPosition::Top => {
-                        // Get `y` coordinate based on `x1, y1` from top-left corner + window scroll
-                        let top = html_element.offset_top() as f64;
-
-                        // Subtract the tooltip height and add an offset
-                        let top = top - tooltip_rect.height() - offset;
-
-                        // If the arrow is enabled, we need to add the arrow size
-                        let top = if arrow {
-                            top - TOOLTIP_ARROW_SIZE as f64
-                        } else {
-                            top
-                        };
-
-                        Coordinates {
-                            top,
-                            left: html_element_rect.left() + html_element_rect.width() / 2.0
-                                - tooltip_rect.width() / 2.0,
-                        }
-                    }
-                    TooltipPosition::Bottom => {
-                        // Get `y` coordinate based on `x4, y4` from bottom-left corner + window scroll
-                        let top = html_element_rect.bottom()
-                            + window().scroll_y().expect("Must have window on the page")
-                            + offset;
-
-                        // If the arrow is enabled, we need to add the arrow size
-                        let top = if arrow {
-                            top + TOOLTIP_ARROW_SIZE as f64
-                        } else {
-                            top
-                        };
-
-                        Coordinates {
-                            top,
-                            left: html_element_rect.left() + html_element_rect.width() / 2.0
-                                - tooltip_rect.width() / 2.0,
-                        }
-                    }
-                    TooltipPosition::Right => {
-                        // Get `y` coordinate based on `x1, y1` from top-left corner + window scroll
-                        let top = html_element.offset_top() as f64;
-                        let top =
-                            top + html_element_rect.height() / 2.0 - tooltip_rect.height() / 2.0;
-
-                        let left = html_element_rect.right() + offset;
-                        let left = if arrow {
-                            left + TOOLTIP_ARROW_SIZE as f64
-                        } else {
-                            left
-                        };
-
-                        Coordinates { top, left }
-                    }
-                    TooltipPosition::Left => {
-                        // Get `y` coordinate based on `x1, y1` from top-left corner + window scroll
-                        let top = html_element.offset_top() as f64;
-                        let top =
-                            top + html_element_rect.height() / 2.0 - tooltip_rect.height() / 2.0;
-
-                        let left = html_element_rect.left() - tooltip_rect.width() - offset;
-                        let left = if arrow {
-                            left - TOOLTIP_ARROW_SIZE as f64
-                        } else {
-                            left
-                        };
-
-                        Coordinates { top, left }
-                    }
-                };
-
-                visible.set(true);
-                coordinates.set(tooltip_coordinates);
+    // Opens or closes the tooltip after `delay` milliseconds,
+    //  replacing any pending change
+    let change = {
+        let internal = visible_state.clone();
+        let on_open_change = on_open_change.clone();
+        let timeout = timeout.clone();
+
+        Rc::new(move |value: bool, delay: u32| {
+            timeout.borrow_mut().take();
+
+            if value == visible {
+                return;
+            }
+
+            let apply = {
+                let internal = internal.clone();
+                let on_open_change = on_open_change.clone();
+
+                move || {
+                    internal.set(value);
+                    on_open_change.emit(value);
+                }
+            };
+
+            if delay == 0 {
+                apply();
+            } else {
+                *timeout.borrow_mut() = Some(Timeout::new(delay, apply));
             }
         })
     };
 
-    let on_mouse_leave = use_callback((), {
-        let visible = visible.clone();
+    let has = |trigger| triggers.contains(&trigger);
+    let show = {
+        let change = change.clone();
+        let delay = *show_delay;
+
+        move || change(true, delay)
+    };
+    let hide = {
+        let change = change.clone();
+        let delay = *hide_delay;
+
+        move || change(false, delay)
+    };
+
+    let on_mouse_enter = has(TooltipTrigger::Hover).then(|| {
+        let show = show.clone();
+        Callback::from(move |_: MouseEvent| show())
+    });
+    let on_mouse_leave = has(TooltipTrigger::Hover).then(|| {
+        let hide = hide.clone();
+        Callback::from(move |_: MouseEvent| hide())
+    });
+    let on_focus_in = has(TooltipTrigger::Focus).then(|| {
+        let show = show.clone();
+        Callback::from(move |_: FocusEvent| show())
+    });
+    let on_focus_out = has(TooltipTrigger::Focus).then(|| {
+        let hide = hide.clone();
+        Callback::from(move |_: FocusEvent| hide())
+    });
+    let on_click = has(TooltipTrigger::Click).then(|| {
+        let change = change.clone();
+        Callback::from(move |_: MouseEvent| change(!visible, 0))
+    });
+
+    // Escape hides the tooltip before anything else, e.g. before closing the modal around it.
+    //  The window listener of the overlays runs later and skips the consumed event.
+    use_effect_with(visible, {
+        let change = change.clone();
+
+        move |visible| {
+            let listener = visible.then(|| {
+                let callback =
+                    Closure::<dyn Fn(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+                        if event.key() == "Escape" && !event.default_prevented() {
+                            event.prevent_default();
+                            change(false, 0);
+                        }
+                    });
+                let _ = window().add_event_listener_with_callback_and_bool(
+                    "keydown",
+                    callback.as_ref().unchecked_ref(),
+                    true,
+                );
+
+                callback
+            });
+
+            move || {
+                if let Some(callback) = listener {
+                    let _ = window().remove_event_listener_with_callback_and_bool(
+                        "keydown",
+                        callback.as_ref().unchecked_ref(),
+                        true,
+                    );
+                }
+            }
+        }
+    });
+
+    use_click_away(anchor_ref.clone(), {
+        let change = change.clone();
+        let click = has(TooltipTrigger::Click);
 
-        move |_event, _| {
-            // We mustn't erase the tooltip coordinates
-            //  otherwise the tooltip will be placed incorrectly
-            //  during hide animation
-            visible.set(false);
+        move |_event: Event| {
+            if click && visible {
+                change(false, 0);
+            }
         }
     });
 
-    html! {
-        <span onmouseenter={on_mouse_enter} onmouseleave={on_mouse_leave} class={classes!(class.clone(), "mm-inline-block")}>
+    // Describe the first child element, which is usually the focusable one, next to
+    //  the descriptions it already has, e.g. a form hint. Again when the children change
+    use_effect_with((id.clone(), children.clone()), {
+        let anchor_ref = anchor_ref.clone();
+
+        move |(id, _)| {
+            let target = anchor_ref
+                .cast::<Element>()
+                .map(|anchor| anchor.first_element_child().unwrap_or(anchor));
+            // Descriptions of the target, without the tooltip
+            let described_by = {
+                let id = id.clone();
+
+                move |target: &Element| {
+                    target
+                        .get_attribute("aria-describedby")
+                        .unwrap_or_default()
+                        .split_whitespace()
+                        .filter(|described_by| *described_by != id.as_str())
+                        .map(str::to_string)
+                        .collect::<Vec<_>>()
+                }
+            };
+
+            if let Some(target) = &target {
+                let mut ids = described_by(target);
+                ids.push(id.to_string());
+                let _ = target.set_attribute("aria-describedby", &ids.join(" "));
+            }
+
+            move || {
+                if let Some(target) = target {
+                    let ids = described_by(&target);
+                    let _ = if ids.is_empty() {
+                        target.remove_attribute("aria-describedby")
+                    } else {
+                        target.set_attribute("aria-describedby", &ids.join(" "))
+                    };
+                }
+            }
+        }
+    });
+
+    // Rendered into `body` so scrolling containers, `overflow: hidden`
+    //  and transformed ancestors can't clip or offset the tooltip
+    let tooltip = create_portal(
+        html! {
             <div
+                id={(*id).clone()}
+                role="tooltip"
                 class={final_classes}
-                style={
-                    format!(
-                        "top: {top}px; left: {left}px; --mm-tooltip-arrow-size: {size}px;",
-                        top = coordinates.top,
-                        left = coordinates.left,
-                        size = TOOLTIP_ARROW_SIZE
-                    )
-                }
+                // Above every open overlay, e.g. on a button of a modal
+                style={floating_style(&coordinates, top_z_index())}
                 ref={tooltip_ref}
             >
                 { title.clone() }
             </div>
+        },
+        body().into(),
+    );
+
+    html! {
+        <span
+            onmouseenter={on_mouse_enter}
+            onmouseleave={on_mouse_leave}
+            onfocusin={on_focus_in}
+            onfocusout={on_focus_out}
+            onclick={on_click}
+            class={classes!(class.clone(), "mm-inline-block")}
+            ref={anchor_ref}
+        >
             { for children.iter() }
+            { tooltip }
         </span>
     }
 }
@@ -0,0 +1,66 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::{body, window};
+use yew::prelude::*;
+
+/// Whether the user asked the system to minimize non-essential motion.
+pub(crate) fn prefers_reduced_motion() -> bool {
+    window()
+        .match_media("(prefers-reduced-motion: reduce)")
+        .ok()
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Transition {
+    /// Whether the element has to be rendered, it stays mounted during the exit transition.
+    pub mounted: bool,
+
+    /// Whether the element has to get its visible classes, the other ones are the
+    ///  starting point of the enter transition and the end point of the exit one.
+    pub shown: bool,
+}
+
+/// Mount and unmount transitions driven by CSS classes.
+///
+/// When `open` is set the element is mounted with its hidden classes, then shown on the
+///  next render so the browser transitions between them. When `open` is cleared it gets
+///  its hidden classes back and stays mounted for `duration` milliseconds, the length of
+///  its CSS transition. With `prefers-reduced-motion` it is unmounted right away.
+#[hook]
+pub(crate) fn use_transition(open: bool, duration: u32) -> Transition {
+    let mounted = use_state_eq(|| false);
+    let shown = use_state_eq(|| false);
+    let timeout = use_mut_ref(|| None::<Timeout>);
+
+    use_effect_with(open, {
+        let mounted = mounted.clone();
+        let shown = shown.clone();
+
+        move |open| {
+            // Dropping the timeout cancels a pending unmount
+            timeout.borrow_mut().take();
+
+            if *open {
+                mounted.set(true);
+                // Apply the hidden classes before the visible ones, or nothing transitions
+                let _ = body().offset_height();
+                shown.set(true);
+            } else {
+                shown.set(false);
+
+                if prefers_reduced_motion() {
+                    mounted.set(false);
+                } else {
+                    *timeout.borrow_mut() =
+                        Some(Timeout::new(duration, move || mounted.set(false)));
+                }
+            }
+        }
+    });
+
+    Transition {
+        mounted: open || *mounted,
+        shown: open && *shown,
+    }
+}
@@ -0,0 +1,450 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    future::{poll_fn, AbortHandle, Abortable},
+    stream::SplitSink,
+    FutureExt, SinkExt, StreamExt,
+};
+use gloo_net::websocket::{futures::WebSocket, Message, State};
+use gloo_timers::future::TimeoutFuture;
+use serde::{de::DeserializeOwned, Serialize};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::components::AvatarConnectionStatus as ConnectionStatus;
+
+/// Exponential delays between reconnection attempts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Backoff {
+    /// Delay before the first attempt, in milliseconds.
+    pub initial_ms: u32,
+
+    /// Upper bound of the delay, in milliseconds.
+    pub max_ms: u32,
+
+    /// Multiplier applied after every failed attempt.
+    pub factor: f64,
+
+    /// Number of attempts after which the hook gives up, `None` to retry forever.
+    pub max_retries: Option<u32>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial_ms: 500,
+            max_ms: 30_000,
+            factor: 2.0,
+            max_retries: None,
+        }
+    }
+}
+
+impl Backoff {
+    /// Delay before the attempt following `failures` failed ones, `None` once `max_retries` is reached.
+    ///
+    /// ```rust
+    /// use ui_common::hooks::Backoff;
+    ///
+    /// let backoff = Backoff { max_retries: Some(6), ..Backoff::default() };
+    ///
+    /// assert_eq!(backoff.delay(0), Some(500));
+    /// assert_eq!(backoff.delay(3), Some(4_000));
+    /// assert_eq!(backoff.delay(5), Some(16_000));
+    /// assert_eq!(backoff.delay(6), None);
+    /// assert_eq!(Backoff::default().delay(40), Some(30_000));
+    /// ```
+    pub fn delay(&self, failures: u32) -> Option<u32> {
+        if self.max_retries.is_some_and(|max| failures >= max) {
+            return None;
+        }
+
+        let delay = self.initial_ms as f64 * self.factor.powi(failures.min(i32::MAX as u32) as i32);
+
+        Some(delay.min(self.max_ms as f64) as u32)
+    }
+}
+
+pub struct UseWebSocketOptions<In> {
+    /// Sub-protocols offered to the server.
+    pub protocols: Vec<String>,
+
+    /// Reconnect when the connection can't be opened or is lost.
+    pub reconnect: bool,
+
+    /// Delays between reconnection attempts.
+    pub backoff: Backoff,
+
+    /// Maximum number of messages kept while disconnected, the oldest ones are dropped first.
+    pub max_queue: usize,
+
+    /// Called with every decoded message, in addition to updating
+    ///  [`UseWebSocketHandle::message`].
+    pub onmessage: Option<Callback<Rc<In>>>,
+}
+
+impl<In> Default for UseWebSocketOptions<In> {
+    fn default() -> Self {
+        Self {
+            protocols: Vec::new(),
+            reconnect: true,
+            backoff: Backoff::default(),
+            max_queue: 100,
+            onmessage: None,
+        }
+    }
+}
+
+pub struct UseWebSocketHandle<In, Out> {
+    /// State of the connection, can be passed to [`Avatar`](crate::components::Avatar) as is.
+    pub status: ConnectionStatus,
+
+    /// Last decoded message.
+    pub message: Option<Rc<In>>,
+
+    /// Last message which couldn't be decoded or encoded.
+    pub error: Option<AttrValue>,
+
+    /// Sends a message as JSON, or queues it until the connection is open.
+    pub send: Callback<Out>,
+
+    /// Opens a new connection, resetting the backoff.
+    pub reconnect: Callback<()>,
+
+    /// Closes the connection and stops reconnecting.
+    pub close: Callback<()>,
+}
+
+impl<In, Out> Clone for UseWebSocketHandle<In, Out> {
+    fn clone(&self) -> Self {
+        Self {
+            status: self.status.clone(),
+            message: self.message.clone(),
+            error: self.error.clone(),
+            send: self.send.clone(),
+            reconnect: self.reconnect.clone(),
+            close: self.close.clone(),
+        }
+    }
+}
+
+/// Messages waiting for a connection, and the channel of the open one.
+struct Outbox {
+    queue: VecDeque<String>,
+    sender: Option<UnboundedSender<String>>,
+    max_queue: usize,
+}
+
+impl Outbox {
+    fn push(&mut self, text: String) {
+        let text = match &self.sender {
+            Some(sender) => match sender.unbounded_send(text) {
+                Ok(()) => return,
+                Err(error) => error.into_inner(),
+            },
+            None => text,
+        };
+
+        self.queue.push_back(text);
+        while self.queue.len() > self.max_queue {
+            self.queue.pop_front();
+        }
+    }
+}
+
+/// Forwards the outgoing messages to the socket, queueing them back when it fails.
+async fn write(
+    mut sink: SplitSink<WebSocket, Message>,
+    mut receiver: UnboundedReceiver<String>,
+    outbox: Rc<RefCell<Outbox>>,
+) {
+    while let Some(text) = receiver.next().await {
+        if sink.send(Message::Text(text.clone())).await.is_err() {
+            let mut outbox = outbox.borrow_mut();
+            outbox.queue.push_front(text);
+            while let Some(Some(text)) = receiver.next().now_or_never() {
+                outbox.queue.push_back(text);
+            }
+            break;
+        }
+    }
+}
+
+/// Connects, reads, and reconnects until the backoff gives up.
+async fn run<In>(
+    url: String,
+    protocols: Vec<String>,
+    reconnect: bool,
+    backoff: Backoff,
+    outbox: Rc<RefCell<Outbox>>,
+    onstatus: Callback<ConnectionStatus>,
+    onmessage: Callback<Result<In, String>>,
+) where
+    In: DeserializeOwned + 'static,
+{
+    let mut failures = 0;
+
+    loop {
+        onstatus.emit(ConnectionStatus::Connecting);
+
+        if let Ok(mut socket) = WebSocket::open_with_protocols(&url, &protocols) {
+            // Resolves once the handshake either succeeded or failed
+            let _ = poll_fn(|cx| socket.poll_ready_unpin(cx)).await;
+
+            if matches!(socket.state(), State::Open) {
+                failures = 0;
+
+                let (sink, mut stream) = socket.split();
+                let (sender, receiver) = mpsc::unbounded();
+                {
+                    let mut outbox = outbox.borrow_mut();
+                    for text in outbox.queue.drain(..) {
+                        let _ = sender.unbounded_send(text);
+                    }
+                    outbox.sender = Some(sender);
+                }
+                spawn_local(write(sink, receiver, outbox.clone()));
+
+                onstatus.emit(ConnectionStatus::Open);
+
+                while let Some(Ok(message)) = stream.next().await {
+                    onmessage.emit(
+                        match message {
+                            Message::Text(text) => serde_json::from_str(&text),
+                            Message::Bytes(bytes) => serde_json::from_slice(&bytes),
+                        }
+                        .map_err(|error| error.to_string()),
+                    );
+                }
+
+                outbox.borrow_mut().sender = None;
+            }
+        }
+
+        onstatus.emit(ConnectionStatus::Closed);
+
+        let Some(delay) = backoff.delay(failures).filter(|_| reconnect) else {
+            break;
+        };
+        failures += 1;
+
+        // Spread the reconnections of many clients after a server restart
+        let jitter = 0.5 + js_sys::Math::random() / 2.0;
+        TimeoutFuture::new((delay as f64 * jitter) as u32).await;
+    }
+}
+
+/// # use_websocket hook
+/// JSON messages over a WebSocket, with automatic reconnection and an outgoing queue.
+///
+/// The connection is opened on mount and whenever `url` changes, and closed on unmount.
+///  Messages sent while disconnected are queued and delivered once the connection is open again.
+///
+/// Point `url` at the local echo server of the `echo_server` example to try it without a backend,
+///  `tests/websocket.rs` runs the hook against it.
+///
+/// ## Example
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use ui_common::{components::Avatar, hooks::{use_websocket, UseWebSocketOptions}};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Chat {
+///     text: String,
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum Page {
+///     Chat,
+/// }
+///
+/// #[function_component(WebSocketExample)]
+/// fn websocket_example() -> Html {
+///     let socket = use_websocket::<Chat, Chat>("ws://127.0.0.1:8080", UseWebSocketOptions::default());
+///
+///     let onclick = {
+///         let send = socket.send.clone();
+///         Callback::from(move |_| send.emit(Chat { text: "Hello".into() }))
+///     };
+///
+///     html! {
+///         <>
+///             <Avatar<Page> connection_status={socket.status.clone()} />
+///             <button {onclick}>{ "Send" }</button>
+///             if let Some(message) = &socket.message {
+///                 <p>{ message.text.clone() }</p>
+///             }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_websocket<In, Out>(
+    url: impl Into<AttrValue>,
+    options: UseWebSocketOptions<In>,
+) -> UseWebSocketHandle<In, Out>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize + 'static,
+{
+    let url = url.into();
+
+    let status = use_state(|| ConnectionStatus::Connecting);
+    let message = use_state(|| None::<Rc<In>>);
+    let error = use_state(|| None::<AttrValue>);
+    // Bumped to open a new connection
+    let generation = use_state(|| 0_u32);
+    let closed = use_state(|| false);
+
+    let outbox = use_mut_ref(|| Outbox {
+        queue: VecDeque::new(),
+        sender: None,
+        max_queue: options.max_queue,
+    });
+    outbox.borrow_mut().max_queue = options.max_queue;
+
+    // The latest callback, so it can change between renders without reconnecting
+    let onmessage = use_mut_ref(|| None::<Callback<Rc<In>>>);
+    *onmessage.borrow_mut() = options.onmessage;
+
+    use_effect_with((url, *generation, *closed), {
+        let status = status.clone();
+        let message = message.clone();
+        let error = error.clone();
+        let outbox = outbox.clone();
+        let UseWebSocketOptions {
+            protocols,
+            reconnect,
+            backoff,
+            ..
+        } = options;
+
+        move |(url, _, closed)| {
+            let abort = if *closed {
+                status.set(ConnectionStatus::Closed);
+                None
+            } else {
+                let onstatus = Callback::from(move |new| status.set(new));
+                let onmessage = Callback::from(move |decoded: Result<In, String>| match decoded {
+                    Ok(decoded) => {
+                        let decoded = Rc::new(decoded);
+                        if let Some(onmessage) = &*onmessage.borrow() {
+                            onmessage.emit(decoded.clone());
+                        }
+                        message.set(Some(decoded));
+                    }
+                    Err(decode_error) => error.set(Some(AttrValue::from(decode_error))),
+                });
+
+                let connection = run(
+                    url.to_string(),
+                    protocols,
+                    reconnect,
+                    backoff,
+                    outbox.clone(),
+                    onstatus,
+                    onmessage,
+                );
+                let (handle, registration) = AbortHandle::new_pair();
+                spawn_local(async move {
+                    let _ = Abortable::new(connection, registration).await;
+                });
+
+                Some(handle)
+            };
+
+            move || {
+                if let Some(abort) = abort {
+                    abort.abort();
+                }
+                // Dropping the sender ends the writer, which closes the socket
+                outbox.borrow_mut().sender = None;
+            }
+        }
+    });
+
+    let send = {
+        let outbox = outbox.clone();
+        let error = error.clone();
+
+        Callback::from(move |out: Out| match serde_json::to_string(&out) {
+            Ok(text) => outbox.borrow_mut().push(text),
+            Err(encode_error) => error.set(Some(AttrValue::from(encode_error.to_string()))),
+        })
+    };
+
+    let reconnect = {
+        let generation = generation.clone();
+        let closed = closed.clone();
+
+        Callback::from(move |_| {
+            closed.set(false);
+            generation.set(generation.wrapping_add(1));
+        })
+    };
+
+    let close = Callback::from(move |_| closed.set(true));
+
+    UseWebSocketHandle {
+        status: (*status).clone(),
+        message: (*message).clone(),
+        error: (*error).clone(),
+        send,
+        reconnect,
+        close,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Backoff;
+
+    #[test]
+    fn delay_grows_by_factor() {
+        let backoff = Backoff::default();
+
+        assert_eq!(backoff.delay(0), Some(500));
+        assert_eq!(backoff.delay(1), Some(1_000));
+        assert_eq!(backoff.delay(2), Some(2_000));
+        assert_eq!(backoff.delay(4), Some(8_000));
+    }
+
+    #[test]
+    fn delay_is_capped() {
+        let backoff = Backoff {
+            initial_ms: 100,
+            max_ms: 1_000,
+            factor: 3.0,
+            max_retries: None,
+        };
+
+        assert_eq!(backoff.delay(2), Some(900));
+        assert_eq!(backoff.delay(3), Some(1_000));
+        assert_eq!(backoff.delay(u32::MAX), Some(1_000));
+    }
+
+    #[test]
+    fn delay_stops_after_max_retries() {
+        let backoff = Backoff {
+            max_retries: Some(2),
+            ..Backoff::default()
+        };
+
+        assert_eq!(backoff.delay(1), Some(1_000));
+        assert_eq!(backoff.delay(2), None);
+        assert_eq!(backoff.delay(3), None);
+    }
+
+    #[test]
+    fn constant_delay_with_factor_one() {
+        let backoff = Backoff {
+            factor: 1.0,
+            ..Backoff::default()
+        };
+
+        assert_eq!(backoff.delay(0), Some(500));
+        assert_eq!(backoff.delay(10), Some(500));
+    }
+}
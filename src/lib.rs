@@ -1,4 +1,5 @@
 pub mod components;
+pub mod hooks;
 pub mod prelude;
 
 mod icons;
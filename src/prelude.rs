@@ -1,2 +1,3 @@
 pub use crate::components::*;
+pub use crate::hooks::*;
 pub use crate::Icon;
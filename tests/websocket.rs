@@ -0,0 +1,147 @@
+//! `use_websocket` against the local echo server of `examples/echo_server.rs`.
+//!
+//! ```sh
+//! cargo run --example echo_server &
+//! wasm-pack test --headless --firefox -- --test websocket
+//! ```
+//!
+//! `ECHO_URL` overrides the address of the server at compile time.
+#![cfg(target_arch = "wasm32")]
+
+use std::{cell::RefCell, rc::Rc};
+
+use gloo_timers::future::TimeoutFuture;
+use gloo_utils::{body, document};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use ui_common::{
+    components::AvatarConnectionStatus as ConnectionStatus,
+    hooks::{use_websocket, Backoff, UseWebSocketHandle, UseWebSocketOptions},
+};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ECHO_URL: &str = match option_env!("ECHO_URL") {
+    Some(url) => url,
+    None => "ws://127.0.0.1:8080",
+};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Echo {
+    text: String,
+}
+
+type Handle = UseWebSocketHandle<Echo, Value>;
+
+/// Latest handle returned by the hook, shared with the test.
+#[derive(Clone, Default)]
+struct Latest(Rc<RefCell<Option<Handle>>>);
+
+impl PartialEq for Latest {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Latest {
+    fn get(&self) -> Handle {
+        self.0.borrow().clone().expect("Probe must be rendered")
+    }
+
+    /// Waits up to 5 seconds for `condition` to hold on the latest handle.
+    async fn wait_for(&self, what: &str, condition: impl Fn(&Handle) -> bool) {
+        for _ in 0..500 {
+            if self.0.borrow().as_ref().is_some_and(&condition) {
+                return;
+            }
+            TimeoutFuture::new(10).await;
+        }
+
+        panic!("Timed out waiting for {what}");
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct ProbeProps {
+    latest: Latest,
+}
+
+#[function_component]
+fn Probe(ProbeProps { latest }: &ProbeProps) -> Html {
+    let socket = use_websocket(
+        ECHO_URL,
+        UseWebSocketOptions {
+            backoff: Backoff {
+                initial_ms: 50,
+                max_ms: 200,
+                ..Backoff::default()
+            },
+            ..UseWebSocketOptions::default()
+        },
+    );
+    *latest.0.borrow_mut() = Some(socket);
+
+    html! {}
+}
+
+fn echo(text: &str) -> Option<Echo> {
+    Some(Echo { text: text.into() })
+}
+
+#[wasm_bindgen_test]
+async fn queues_decodes_and_reconnects() {
+    let latest = Latest::default();
+    let root = document().create_element("div").unwrap();
+    body().append_child(&root).unwrap();
+    yew::Renderer::<Probe>::with_root_and_props(
+        root,
+        ProbeProps {
+            latest: latest.clone(),
+        },
+    )
+    .render();
+
+    // Sent before the connection is open, delivered once it is
+    latest.wait_for("the first render", |_| true).await;
+    assert_ne!(latest.get().status, ConnectionStatus::Open);
+    latest.get().send.emit(json!({ "text": "queued" }));
+
+    latest
+        .wait_for("the connection", |socket| {
+            socket.status == ConnectionStatus::Open
+        })
+        .await;
+    latest
+        .wait_for("the queued message", |socket| {
+            socket.message.as_deref() == echo("queued").as_ref()
+        })
+        .await;
+
+    // Messages which don't match `In` are reported, not dropped silently
+    latest.get().send.emit(json!({ "number": 1 }));
+    latest
+        .wait_for("the decoding error", |socket| socket.error.is_some())
+        .await;
+
+    // The server drops the connection, the hook opens a new one
+    latest.get().send.emit(json!({ "text": "drop" }));
+    latest
+        .wait_for("the disconnection", |socket| {
+            socket.status != ConnectionStatus::Open
+        })
+        .await;
+    latest
+        .wait_for("the reconnection", |socket| {
+            socket.status == ConnectionStatus::Open
+        })
+        .await;
+
+    latest.get().send.emit(json!({ "text": "again" }));
+    latest
+        .wait_for("the message after reconnecting", |socket| {
+            socket.message.as_deref() == echo("again").as_ref()
+        })
+        .await;
+}